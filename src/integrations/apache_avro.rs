@@ -0,0 +1,93 @@
+use super::conversion_error::ConversionError;
+use crate::{Interval, RoundingMode};
+use apache_avro::Duration;
+use apache_avro::types::Value;
+
+impl TryFrom<Interval> for Value {
+    type Error = ConversionError;
+    /// Converts the interval into a `Value::Duration`, with the same rules as
+    /// `Interval::to_avro_duration_bytes`. Truncates any microseconds that are not
+    /// whole milliseconds, use that method to round them instead.
+    fn try_from(interval: Interval) -> Result<Value, ConversionError> {
+        let bytes = interval.to_avro_duration_bytes(RoundingMode::Truncate)?;
+        Ok(Value::Duration(Duration::from(bytes)))
+    }
+}
+
+impl TryFrom<Value> for Interval {
+    type Error = ConversionError;
+    /// Only `Value::Duration` can be converted. The months and days have to fit into
+    /// the interval's signed fields.
+    fn try_from(value: Value) -> Result<Interval, ConversionError> {
+        match value {
+            Value::Duration(duration) => Interval::from_avro_duration_bytes(duration.into()),
+            _ => Err(ConversionError::from_unrepresentable(
+                "Only avro duration values can be converted to an interval.",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apache_avro::{Days, Millis, Months};
+
+    fn duration(months: u32, days: u32, millis: u32) -> Value {
+        Value::Duration(Duration::new(
+            Months::new(months),
+            Days::new(days),
+            Millis::new(millis),
+        ))
+    }
+
+    #[test]
+    fn test_to_value() {
+        let interval = Interval::new(14, 3, 4_005_999);
+        assert_eq!(Value::try_from(interval), Ok(duration(14, 3, 4_005)));
+        assert_eq!(Value::try_from(Interval::ZERO), Ok(duration(0, 0, 0)));
+    }
+
+    #[test]
+    fn test_to_value_errors() {
+        assert!(matches!(
+            Value::try_from(Interval::new(0, -1, 0)),
+            Err(ConversionError::Negative(_))
+        ));
+        assert!(matches!(
+            Value::try_from(Interval::new(0, 0, (u32::MAX as i64 + 1) * 1_000)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            Value::try_from(Interval::INFINITY),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_value() {
+        assert_eq!(
+            Interval::try_from(duration(14, 3, 4_005)),
+            Ok(Interval::new(14, 3, 4_005_000))
+        );
+        let max = duration(i32::MAX as u32, i32::MAX as u32, u32::MAX);
+        let interval = Interval::try_from(max.clone()).unwrap();
+        assert_eq!(Value::try_from(interval), Ok(max));
+    }
+
+    #[test]
+    fn test_from_value_errors() {
+        assert!(matches!(
+            Interval::try_from(duration(u32::MAX, 0, 0)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            Interval::try_from(duration(0, i32::MAX as u32 + 1, 0)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            Interval::try_from(Value::Long(1_000)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+    }
+}
@@ -0,0 +1,326 @@
+use super::conversion_error::ConversionError;
+use crate::pg_interval::{MICROS_PER_MILLI, NANOS_PER_MICRO};
+use crate::{Interval, RoundingMode};
+use arrow_array::types::{IntervalDayTime, IntervalMonthDayNano};
+use arrow_array::{IntervalDayTimeArray, IntervalMonthDayNanoArray, IntervalYearMonthArray};
+
+impl Interval {
+    /// Converts the arrow interval into an interval, rounding any nanoseconds that
+    /// are not whole microseconds using the rounding mode.
+    pub fn from_month_day_nano(value: IntervalMonthDayNano, rounding: RoundingMode) -> Interval {
+        Interval::new(
+            value.months,
+            value.days,
+            rounding.div_thousand(value.nanoseconds),
+        )
+    }
+
+    /// Converts the intervals into an arrow array, with `None` becoming null.
+    pub fn to_month_day_nano_array<I, T>(
+        intervals: I,
+    ) -> Result<IntervalMonthDayNanoArray, ConversionError>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Option<Interval>>,
+    {
+        intervals
+            .into_iter()
+            .map(|interval| {
+                interval
+                    .into()
+                    .map(IntervalMonthDayNano::try_from)
+                    .transpose()
+            })
+            .collect()
+    }
+
+    /// Converts the arrow array into intervals, with nulls becoming `None` and
+    /// nanoseconds that are not whole microseconds rounded using the rounding mode.
+    pub fn from_month_day_nano_array(
+        array: &IntervalMonthDayNanoArray,
+        rounding: RoundingMode,
+    ) -> Vec<Option<Interval>> {
+        array
+            .iter()
+            .map(|value| value.map(|value| Interval::from_month_day_nano(value, rounding)))
+            .collect()
+    }
+
+    /// Converts the intervals into an arrow array of months, with `None` becoming
+    /// null. Only intervals without days or time can be converted.
+    pub fn to_year_month_array<I, T>(
+        intervals: I,
+    ) -> Result<IntervalYearMonthArray, ConversionError>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Option<Interval>>,
+    {
+        intervals
+            .into_iter()
+            .map(|interval| interval.into().map(Interval::to_year_month).transpose())
+            .collect()
+    }
+
+    /// Converts the arrow array of months into intervals, with nulls becoming `None`.
+    pub fn from_year_month_array(array: &IntervalYearMonthArray) -> Vec<Option<Interval>> {
+        array
+            .iter()
+            .map(|months| months.map(|months| Interval::new(months, 0, 0)))
+            .collect()
+    }
+
+    /// Converts the intervals into an arrow array of days and milliseconds, with
+    /// `None` becoming null. Only intervals without months can be converted.
+    pub fn to_day_time_array<I, T>(intervals: I) -> Result<IntervalDayTimeArray, ConversionError>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Option<Interval>>,
+    {
+        intervals
+            .into_iter()
+            .map(|interval| interval.into().map(IntervalDayTime::try_from).transpose())
+            .collect()
+    }
+
+    /// Converts the arrow array of days and milliseconds into intervals, with nulls
+    /// becoming `None`.
+    pub fn from_day_time_array(array: &IntervalDayTimeArray) -> Vec<Option<Interval>> {
+        array
+            .iter()
+            .map(|value| value.map(Interval::from))
+            .collect()
+    }
+
+    /// The months of an interval without days or time, the way arrow's
+    /// `IntervalYearMonth` type stores it.
+    fn to_year_month(self) -> Result<i32, ConversionError> {
+        if self.is_infinite() {
+            return Err(ConversionError::from_out_of_range(
+                "Infinite intervals can not be converted to an arrow interval.",
+            ));
+        }
+        if self.days != 0 || self.microseconds != 0 {
+            return Err(ConversionError::from_unrepresentable(
+                "Intervals with days or time can not be converted to a year month interval.",
+            ));
+        }
+        Ok(self.months)
+    }
+}
+
+impl From<IntervalMonthDayNano> for Interval {
+    /// Truncates any nanoseconds that are not whole microseconds, use
+    /// `Interval::from_month_day_nano` to round them instead.
+    fn from(value: IntervalMonthDayNano) -> Interval {
+        Interval::from_month_day_nano(value, RoundingMode::Truncate)
+    }
+}
+
+impl TryFrom<Interval> for IntervalMonthDayNano {
+    type Error = ConversionError;
+    /// The months and days are kept as they are, but arrow stores the time in
+    /// nanoseconds which only covers about 292 years.
+    fn try_from(interval: Interval) -> Result<IntervalMonthDayNano, ConversionError> {
+        if interval.is_infinite() {
+            return Err(ConversionError::from_out_of_range(
+                "Infinite intervals can not be converted to an arrow interval.",
+            ));
+        }
+        interval
+            .microseconds
+            .checked_mul(NANOS_PER_MICRO)
+            .map(|nanos| IntervalMonthDayNano::new(interval.months, interval.days, nanos))
+            .ok_or_else(|| {
+                ConversionError::from_out_of_range(
+                    "Interval time exceeds the range of arrow's nanoseconds.",
+                )
+            })
+    }
+}
+
+impl From<IntervalDayTime> for Interval {
+    fn from(value: IntervalDayTime) -> Interval {
+        Interval::new(0, value.days, value.milliseconds as i64 * MICROS_PER_MILLI)
+    }
+}
+
+impl TryFrom<Interval> for IntervalDayTime {
+    type Error = ConversionError;
+    /// Arrow stores the time in milliseconds as an `i32`, so the interval can't have
+    /// months, its time has to be whole milliseconds and can't exceed about 24 days.
+    fn try_from(interval: Interval) -> Result<IntervalDayTime, ConversionError> {
+        if interval.is_infinite() {
+            return Err(ConversionError::from_out_of_range(
+                "Infinite intervals can not be converted to an arrow interval.",
+            ));
+        }
+        if interval.months != 0 {
+            return Err(ConversionError::from_unrepresentable(
+                "Intervals with months can not be converted to a day time interval.",
+            ));
+        }
+        if interval.microseconds % MICROS_PER_MILLI != 0 {
+            return Err(ConversionError::from_unrepresentable(
+                "Intervals with fractional milliseconds can not be converted to a day time interval.",
+            ));
+        }
+        i32::try_from(interval.microseconds / MICROS_PER_MILLI)
+            .map(|millis| IntervalDayTime::new(interval.days, millis))
+            .map_err(|_| {
+                ConversionError::from_out_of_range(
+                    "Interval time exceeds the range of arrow's milliseconds.",
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Array;
+
+    #[test]
+    fn test_month_day_nano_round_trip() {
+        let interval = Interval::new(14, -3, 4_000_005);
+        let value = IntervalMonthDayNano::try_from(interval).unwrap();
+        assert_eq!(value, IntervalMonthDayNano::new(14, -3, 4_000_005_000));
+        assert_eq!(Interval::from(value), interval);
+    }
+
+    #[test]
+    fn test_month_day_nano_rounding() {
+        let value = IntervalMonthDayNano::new(1, 2, -1_500);
+        assert_eq!(Interval::from(value), Interval::new(1, 2, -1));
+        assert_eq!(
+            Interval::from_month_day_nano(value, RoundingMode::HalfEven),
+            Interval::new(1, 2, -2)
+        );
+        assert_eq!(
+            Interval::from_month_day_nano(
+                IntervalMonthDayNano::new(0, 0, 2_500),
+                RoundingMode::HalfEven
+            ),
+            Interval::new(0, 0, 2)
+        );
+        assert_eq!(
+            Interval::from_month_day_nano(
+                IntervalMonthDayNano::new(0, 0, 2_500),
+                RoundingMode::HalfUp
+            ),
+            Interval::new(0, 0, 3)
+        );
+    }
+
+    #[test]
+    fn test_month_day_nano_limits() {
+        let limit = Interval::new(i32::MAX, i32::MIN, i64::MAX / NANOS_PER_MICRO);
+        assert!(IntervalMonthDayNano::try_from(limit).is_ok());
+        let interval = Interval::new(0, 0, i64::MAX / NANOS_PER_MICRO + 1);
+        assert!(matches!(
+            IntervalMonthDayNano::try_from(interval),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            IntervalMonthDayNano::try_from(Interval::INFINITY),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        let value = IntervalMonthDayNano::new(i32::MIN, i32::MAX, i64::MIN);
+        assert_eq!(
+            Interval::from(value),
+            Interval::new(i32::MIN, i32::MAX, i64::MIN / NANOS_PER_MICRO)
+        );
+    }
+
+    #[test]
+    fn test_month_day_nano_array() {
+        let intervals = [Interval::new(1, 2, 3), Interval::new(-1, 0, -3)];
+        let array = Interval::to_month_day_nano_array(intervals).unwrap();
+        assert_eq!(array.null_count(), 0);
+        assert_eq!(array.value(1), IntervalMonthDayNano::new(-1, 0, -3_000));
+        assert_eq!(
+            Interval::from_month_day_nano_array(&array, RoundingMode::Truncate),
+            vec![Some(intervals[0]), Some(intervals[1])]
+        );
+    }
+
+    #[test]
+    fn test_month_day_nano_array_nulls() {
+        let intervals = vec![Some(Interval::new(1, 2, 3)), None];
+        let array = Interval::to_month_day_nano_array(intervals.iter().copied()).unwrap();
+        assert!(array.is_null(1));
+        assert_eq!(
+            Interval::from_month_day_nano_array(&array, RoundingMode::Truncate),
+            intervals
+        );
+        let error = Interval::to_month_day_nano_array([Some(Interval::NEG_INFINITY), None]);
+        assert!(matches!(error, Err(ConversionError::OutOfRange(_))));
+    }
+
+    #[test]
+    fn test_year_month_array() {
+        let intervals = vec![
+            Some(Interval::new(14, 0, 0)),
+            None,
+            Some(Interval::new(-1, 0, 0)),
+        ];
+        let array = Interval::to_year_month_array(intervals.iter().copied()).unwrap();
+        assert_eq!(array.value(0), 14);
+        assert!(array.is_null(1));
+        assert_eq!(Interval::from_year_month_array(&array), intervals);
+    }
+
+    #[test]
+    fn test_year_month_array_invalid() {
+        assert!(matches!(
+            Interval::to_year_month_array([Interval::new(1, 1, 0)]),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+        assert!(matches!(
+            Interval::to_year_month_array([Interval::new(1, 0, 1)]),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+        assert!(matches!(
+            Interval::to_year_month_array([Interval::INFINITY]),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_day_time_round_trip() {
+        let interval = Interval::new(0, -3, 4_005_000);
+        let value = IntervalDayTime::try_from(interval).unwrap();
+        assert_eq!(value, IntervalDayTime::new(-3, 4_005));
+        assert_eq!(Interval::from(value), interval);
+    }
+
+    #[test]
+    fn test_day_time_invalid() {
+        assert!(matches!(
+            IntervalDayTime::try_from(Interval::new(1, 0, 0)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+        assert!(matches!(
+            IntervalDayTime::try_from(Interval::new(0, 0, 1)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+        let millis = i32::MAX as i64 + 1;
+        assert!(matches!(
+            IntervalDayTime::try_from(Interval::new(0, 0, millis * MICROS_PER_MILLI)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            IntervalDayTime::try_from(Interval::NEG_INFINITY),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_day_time_array() {
+        let intervals = vec![None, Some(Interval::new(0, 1, 2_000)), Some(Interval::ZERO)];
+        let array = Interval::to_day_time_array(intervals.iter().copied()).unwrap();
+        assert!(array.is_null(0));
+        assert_eq!(array.value(1), IntervalDayTime::new(1, 2));
+        assert_eq!(Interval::from_day_time_array(&array), intervals);
+        assert!(Interval::to_day_time_array([Interval::new(1, 0, 0)]).is_err());
+    }
+}
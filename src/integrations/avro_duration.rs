@@ -0,0 +1,153 @@
+//! Avro's `duration` and parquet's `INTERVAL` byte layout. It only needs the
+//! standard library so it is always compiled, for writers that produce the bytes
+//! themselves. The `apache-avro` feature builds the `Value::Duration` conversions
+//! on top of it.
+use super::conversion_error::ConversionError;
+use crate::pg_interval::MICROS_PER_MILLI;
+use crate::{Interval, RoundingMode};
+
+/// The length of avro's `duration` and parquet's `INTERVAL` values.
+const AVRO_DURATION_LEN: usize = 12;
+
+impl Interval {
+    /// Encodes the interval the way avro's `duration` logical type and parquet's
+    /// legacy `INTERVAL` type store it, the months followed by the days and the
+    /// milliseconds as unsigned little endian integers. Microseconds that are not
+    /// whole milliseconds are rounded using the rounding mode.
+    ///
+    /// The format can't store negative values and only covers about 49 days of
+    /// milliseconds.
+    pub fn to_avro_duration_bytes(
+        &self,
+        rounding: RoundingMode,
+    ) -> Result<[u8; AVRO_DURATION_LEN], ConversionError> {
+        if self.is_infinite() {
+            return Err(ConversionError::from_out_of_range(
+                "Infinite intervals can not be converted to an avro duration.",
+            ));
+        }
+        let millis = rounding.div_thousand(self.microseconds);
+        if self.months < 0 || self.days < 0 || millis < 0 {
+            return Err(ConversionError::from_negative(
+                "Negative intervals can not be converted to an avro duration.",
+            ));
+        }
+        let millis = u32::try_from(millis).map_err(|_| {
+            ConversionError::from_out_of_range(
+                "Interval time exceeds the range of an avro duration's milliseconds.",
+            )
+        })?;
+        let mut out = [0; AVRO_DURATION_LEN];
+        out[..4].copy_from_slice(&(self.months as u32).to_le_bytes());
+        out[4..8].copy_from_slice(&(self.days as u32).to_le_bytes());
+        out[8..].copy_from_slice(&millis.to_le_bytes());
+        Ok(out)
+    }
+
+    /// Decodes an interval from avro's `duration` logical type or parquet's legacy
+    /// `INTERVAL` type. The months and days have to fit into the interval's signed
+    /// fields.
+    pub fn from_avro_duration_bytes(
+        raw: [u8; AVRO_DURATION_LEN],
+    ) -> Result<Interval, ConversionError> {
+        let (months, rest) = raw.split_at(4);
+        let (days, millis) = rest.split_at(4);
+        // the slices have the right lengths so the conversions can't fail.
+        let months = u32::from_le_bytes(months.try_into().unwrap());
+        let days = u32::from_le_bytes(days.try_into().unwrap());
+        let millis = u32::from_le_bytes(millis.try_into().unwrap());
+        let months = i32::try_from(months).map_err(|_| {
+            ConversionError::from_out_of_range("Avro duration months exceed the interval range.")
+        })?;
+        let days = i32::try_from(days).map_err(|_| {
+            ConversionError::from_out_of_range("Avro duration days exceed the interval range.")
+        })?;
+        Ok(Interval::new(
+            months,
+            days,
+            millis as i64 * MICROS_PER_MILLI,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_avro_duration_layout() {
+        let interval = Interval::new(14, 3, 4_005_000);
+        let bytes = interval
+            .to_avro_duration_bytes(RoundingMode::Truncate)
+            .unwrap();
+        assert_eq!(bytes, [0x0e, 0, 0, 0, 3, 0, 0, 0, 0xa5, 0x0f, 0, 0]);
+        assert_eq!(Interval::from_avro_duration_bytes(bytes), Ok(interval));
+    }
+
+    #[test]
+    fn test_avro_duration_rounding() {
+        let interval = Interval::new(0, 0, 2_500);
+        let round = |rounding| {
+            Interval::from_avro_duration_bytes(interval.to_avro_duration_bytes(rounding).unwrap())
+                .unwrap()
+        };
+        assert_eq!(round(RoundingMode::Truncate), Interval::new(0, 0, 2_000));
+        assert_eq!(round(RoundingMode::HalfEven), Interval::new(0, 0, 2_000));
+        assert_eq!(round(RoundingMode::HalfUp), Interval::new(0, 0, 3_000));
+    }
+
+    #[test]
+    fn test_avro_duration_negative() {
+        for interval in [
+            Interval::new(-1, 0, 0),
+            Interval::new(0, -1, 0),
+            Interval::new(0, 0, -1_000),
+            Interval::MIN,
+        ] {
+            assert!(matches!(
+                interval.to_avro_duration_bytes(RoundingMode::Truncate),
+                Err(ConversionError::Negative(_))
+            ));
+        }
+        // rounds to zero before the sign is checked.
+        let interval = Interval::new(0, 0, -1);
+        assert_eq!(
+            interval.to_avro_duration_bytes(RoundingMode::Truncate),
+            Ok([0; AVRO_DURATION_LEN])
+        );
+    }
+
+    #[test]
+    fn test_avro_duration_limits() {
+        let max = Interval::new(i32::MAX, i32::MAX, u32::MAX as i64 * MICROS_PER_MILLI);
+        let bytes = max.to_avro_duration_bytes(RoundingMode::Truncate).unwrap();
+        assert_eq!(Interval::from_avro_duration_bytes(bytes), Ok(max));
+        let interval = Interval::new(0, 0, (u32::MAX as i64 + 1) * MICROS_PER_MILLI);
+        assert!(matches!(
+            interval.to_avro_duration_bytes(RoundingMode::Truncate),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            Interval::INFINITY.to_avro_duration_bytes(RoundingMode::Truncate),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_avro_duration_out_of_range() {
+        let mut bytes = [0; AVRO_DURATION_LEN];
+        bytes[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            Interval::from_avro_duration_bytes(bytes),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        let mut bytes = [0; AVRO_DURATION_LEN];
+        bytes[4..8].copy_from_slice(&(i32::MAX as u32 + 1).to_le_bytes());
+        assert!(matches!(
+            Interval::from_avro_duration_bytes(bytes),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        let bytes = [0xff; AVRO_DURATION_LEN];
+        assert!(Interval::from_avro_duration_bytes(bytes).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{Interval, ParseError};
+
+/// The length of an interval in postgres' binary format.
+pub(crate) const BINARY_LEN: usize = 16;
+
+impl Interval {
+    /// Encodes the interval the way postgres sends it in the binary format, the
+    /// microseconds followed by the days and the months in network byte order.
+    pub(crate) fn to_binary(self) -> [u8; BINARY_LEN] {
+        let mut out = [0; BINARY_LEN];
+        out[..8].copy_from_slice(&self.microseconds.to_be_bytes());
+        out[8..12].copy_from_slice(&self.days.to_be_bytes());
+        out[12..].copy_from_slice(&self.months.to_be_bytes());
+        out
+    }
+
+    /// Decodes an interval from postgres' binary format.
+    pub(crate) fn from_binary(raw: &[u8]) -> Result<Interval, ParseError> {
+        let raw: &[u8; BINARY_LEN] = raw.try_into().map_err(|_| {
+            ParseError::from_invalid_interval("Binary interval must be 16 bytes long.")
+        })?;
+        let (microseconds, rest) = raw.split_at(8);
+        let (days, months) = rest.split_at(4);
+        // the slices have the right lengths so the conversions can't fail.
+        Ok(Interval {
+            months: i32::from_be_bytes(months.try_into().unwrap()),
+            days: i32::from_be_bytes(days.try_into().unwrap()),
+            microseconds: i64::from_be_bytes(microseconds.try_into().unwrap()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_layout() {
+        let interval = Interval::new(14, -3, 4_000_000);
+        let bytes = interval.to_binary();
+        assert_eq!(
+            bytes,
+            [
+                0, 0, 0, 0, 0, 0x3d, 0x09, 0, 0xff, 0xff, 0xff, 0xfd, 0, 0, 0, 0x0e
+            ]
+        );
+        assert_eq!(Interval::from_binary(&bytes), Ok(interval));
+    }
+
+    #[test]
+    fn test_binary_wrong_length() {
+        assert!(Interval::from_binary(&[0; 15]).is_err());
+        assert!(Interval::from_binary(&[0; 17]).is_err());
+        assert!(Interval::from_binary(&[]).is_err());
+    }
+}
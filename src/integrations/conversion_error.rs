@@ -0,0 +1,34 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConversionError {
+    OutOfRange(String),
+    Negative(String),
+    Unrepresentable(String),
+}
+
+impl<'a> ConversionError {
+    pub fn from_out_of_range(message: &'a str) -> ConversionError {
+        ConversionError::OutOfRange(String::from(message))
+    }
+
+    pub fn from_negative(message: &'a str) -> ConversionError {
+        ConversionError::Negative(String::from(message))
+    }
+
+    pub fn from_unrepresentable(message: &'a str) -> ConversionError {
+        ConversionError::Unrepresentable(String::from(message))
+    }
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::OutOfRange(s) => write!(f, "Value out of range: {}", s),
+            ConversionError::Negative(s) => write!(f, "Negative value: {}", s),
+            ConversionError::Unrepresentable(s) => write!(f, "Unrepresentable value: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
@@ -0,0 +1,232 @@
+use super::conversion_error::ConversionError;
+use crate::Interval;
+use crate::interval_arithmetic::{IntervalArithmetic, impl_interval_ops};
+use crate::pg_interval::MICROS_PER_SECOND;
+use chrono::{DateTime, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeDelta, TimeZone};
+
+impl<Tz: TimeZone> IntervalArithmetic for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    /// The months and days are added to the local wall clock time while the
+    /// time part is added in absolute time, like postgres does for `timestamptz`.
+    /// So adding `1 day` keeps the same local time across a daylight saving
+    /// transition while adding `24 hours` does not.
+    fn checked_add_interval(self, interval: Interval) -> Option<DateTime<Tz>> {
+        if interval.is_infinite() {
+            return None;
+        }
+        let mut timestamp = self;
+        if interval.months != 0 {
+            timestamp = add_to_local(timestamp, Interval::new(interval.months, 0, 0))?;
+        }
+        if interval.days != 0 {
+            timestamp = add_to_local(timestamp, Interval::new(0, interval.days, 0))?;
+        }
+        timestamp.checked_add_signed(TimeDelta::microseconds(interval.microseconds))
+    }
+}
+
+/// Adds the interval to the local wall clock time of the timestamp.
+fn add_to_local<Tz: TimeZone>(timestamp: DateTime<Tz>, interval: Interval) -> Option<DateTime<Tz>> {
+    let local = timestamp.naive_local().checked_add_interval(interval)?;
+    from_local(&timestamp.timezone(), local)
+}
+
+/// Resolves the local time in the time zone the same way postgres does. A local
+/// time skipped by a spring forward transition uses the offset from before the
+/// transition, and a local time repeated by a fall back transition uses the offset
+/// from after the transition.
+fn from_local<Tz: TimeZone>(tz: &Tz, local: NaiveDateTime) -> Option<DateTime<Tz>> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(timestamp) => Some(timestamp),
+        LocalResult::Ambiguous(_, latest) => Some(latest),
+        LocalResult::None => {
+            // like postgres assume transitions are at least a day apart, so the
+            // offset a day earlier is the one from before the transition.
+            let day_before = local.checked_sub_signed(TimeDelta::days(1))?;
+            let before = tz.offset_from_utc_datetime(&day_before).fix();
+            let utc =
+                local.checked_sub_signed(TimeDelta::seconds(before.local_minus_utc() as i64))?;
+            Some(tz.from_utc_datetime(&utc))
+        }
+    }
+}
+
+impl_interval_ops!(<Tz: TimeZone> DateTime<Tz>);
+
+impl From<FixedOffset> for Interval {
+    /// The offset east of UTC as a time interval, matching the intervals postgres
+    /// accepts for `timezone(interval, timestamp)`.
+    fn from(offset: FixedOffset) -> Interval {
+        Interval::new(0, 0, offset.local_minus_utc() as i64 * MICROS_PER_SECOND)
+    }
+}
+
+impl TryFrom<Interval> for FixedOffset {
+    type Error = ConversionError;
+    /// Treats the interval as the offset east of UTC like postgres'
+    /// `timezone(interval, timestamp)`. Any fractional seconds are truncated.
+    fn try_from(interval: Interval) -> Result<FixedOffset, ConversionError> {
+        if interval.months != 0 || interval.days != 0 {
+            return Err(ConversionError::from_unrepresentable(
+                "Interval time zone must not include months or days.",
+            ));
+        }
+        i32::try_from(interval.microseconds / MICROS_PER_SECOND)
+            .ok()
+            .and_then(FixedOffset::east_opt)
+            .ok_or_else(|| {
+                ConversionError::from_out_of_range("Interval time zone must be less than a day.")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, Utc};
+    use chrono_tz::America::New_York;
+    use chrono_tz::Europe::London;
+
+    const HOUR: i64 = 3_600_000_000;
+
+    fn local(year: i32, month: u32, day: u32, hour: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, min, 0)
+            .unwrap()
+    }
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, min: u32) -> DateTime<Utc> {
+        Utc.from_utc_datetime(&local(year, month, day, hour, min))
+    }
+
+    #[test]
+    fn test_add_day_keeps_wall_clock_across_dst() {
+        // '2024-03-09 12:00 America/New_York' + '1 day' = '2024-03-10 12:00 EDT'
+        let start = New_York
+            .from_local_datetime(&local(2024, 3, 9, 12, 0))
+            .unwrap();
+        let result = start + Interval::new(0, 1, 0);
+        assert_eq!(result.naive_local(), local(2024, 3, 10, 12, 0));
+        assert_eq!(result.with_timezone(&Utc), utc(2024, 3, 10, 16, 0));
+    }
+
+    #[test]
+    fn test_add_hours_uses_absolute_time_across_dst() {
+        // '2024-03-09 12:00 America/New_York' + '24 hours' = '2024-03-10 13:00 EDT'
+        let start = New_York
+            .from_local_datetime(&local(2024, 3, 9, 12, 0))
+            .unwrap();
+        let result = start + Interval::new(0, 0, 24 * HOUR);
+        assert_eq!(result.naive_local(), local(2024, 3, 10, 13, 0));
+    }
+
+    #[test]
+    fn test_add_month_keeps_wall_clock_across_dst() {
+        // '2024-10-15 09:00 Europe/London' + '1 mon' = '2024-11-15 09:00 GMT'
+        let start = London
+            .from_local_datetime(&local(2024, 10, 15, 9, 0))
+            .unwrap();
+        let result = start + Interval::new(1, 0, 0);
+        assert_eq!(result.naive_local(), local(2024, 11, 15, 9, 0));
+        assert_eq!(result.with_timezone(&Utc), utc(2024, 11, 15, 9, 0));
+    }
+
+    #[test]
+    fn test_nonexistent_local_time_uses_offset_before_transition() {
+        // '2024-03-09 02:30 America/New_York' + '1 day' = '2024-03-10 03:30 EDT'
+        let start = New_York
+            .from_local_datetime(&local(2024, 3, 9, 2, 30))
+            .unwrap();
+        let result = start + Interval::new(0, 1, 0);
+        assert_eq!(result.with_timezone(&Utc), utc(2024, 3, 10, 7, 30));
+        assert_eq!(result.naive_local(), local(2024, 3, 10, 3, 30));
+    }
+
+    #[test]
+    fn test_ambiguous_local_time_uses_offset_after_transition() {
+        // '2024-11-02 01:30 America/New_York' + '1 day' = '2024-11-03 01:30 EST'
+        let start = New_York
+            .from_local_datetime(&local(2024, 11, 2, 1, 30))
+            .unwrap();
+        let result = start + Interval::new(0, 1, 0);
+        assert_eq!(result.with_timezone(&Utc), utc(2024, 11, 3, 6, 30));
+    }
+
+    #[test]
+    fn test_sub_day_across_dst() {
+        // '2024-03-10 12:00 America/New_York' - '1 day 1 hour' = '2024-03-09 11:00 EST'
+        let start = New_York
+            .from_local_datetime(&local(2024, 3, 10, 12, 0))
+            .unwrap();
+        let result = start - Interval::new(0, 1, HOUR);
+        assert_eq!(result.naive_local(), local(2024, 3, 9, 11, 0));
+    }
+
+    #[test]
+    fn test_month_end_clamping_in_zone() {
+        let start = New_York
+            .from_local_datetime(&local(2024, 1, 31, 8, 0))
+            .unwrap();
+        let result = Interval::new(1, 0, 0).checked_add_to(start).unwrap();
+        assert_eq!(result.naive_local(), local(2024, 2, 29, 8, 0));
+    }
+
+    #[test]
+    fn test_checked_add_out_of_range() {
+        let interval = Interval::new(0, 1, 0);
+        assert_eq!(interval.checked_add_to(DateTime::<Utc>::MAX_UTC), None);
+    }
+
+    #[test]
+    fn test_infinite_interval() {
+        let start = utc(2024, 1, 1, 0, 0);
+        assert_eq!(Interval::INFINITY.checked_add_to(start), None);
+        assert_eq!(Interval::NEG_INFINITY.checked_sub_from(start), None);
+        assert!(FixedOffset::try_from(Interval::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_fixed_offset_conversion() {
+        let offset = FixedOffset::west_opt(8 * 3600).unwrap();
+        assert_eq!(Interval::from(offset), Interval::new(0, 0, -8 * HOUR));
+        assert_eq!(
+            FixedOffset::try_from(Interval::new(0, 0, -8 * HOUR)),
+            Ok(offset)
+        );
+    }
+
+    #[test]
+    fn test_fixed_offset_truncates_fractional_seconds() {
+        let offset = FixedOffset::try_from(Interval::new(0, 0, 1_500_000)).unwrap();
+        assert_eq!(offset.local_minus_utc(), 1);
+    }
+
+    #[test]
+    fn test_fixed_offset_errors() {
+        assert!(matches!(
+            FixedOffset::try_from(Interval::new(0, 1, 0)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+        assert!(matches!(
+            FixedOffset::try_from(Interval::new(0, 0, 24 * HOUR)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            FixedOffset::try_from(Interval::new(0, 0, i64::MAX)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_timezone_interval() {
+        // timezone(interval '-08:00', timestamp '2001-02-16 20:38:40')
+        //   = '2001-02-17 04:38:40+00'
+        let offset = FixedOffset::try_from(Interval::new(0, 0, -8 * HOUR)).unwrap();
+        let result = offset
+            .from_local_datetime(&local(2001, 2, 16, 20, 38))
+            .unwrap();
+        assert_eq!(result.with_timezone(&Utc), utc(2001, 2, 17, 4, 38));
+    }
+}
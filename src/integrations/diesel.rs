@@ -0,0 +1,83 @@
+use crate::Interval;
+use diesel::deserialize::{self, FromSql};
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types;
+use std::io::Write;
+
+impl FromSql<sql_types::Interval, Pg> for Interval {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Interval> {
+        Ok(Interval::from_binary(bytes.as_bytes())?)
+    }
+}
+
+impl ToSql<sql_types::Interval, Pg> for Interval {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(&self.to_binary())?;
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::pg::data_types::PgInterval;
+    use diesel::pg::{PgMetadataLookup, PgTypeMetadata};
+    use diesel::query_builder::BindCollector;
+    use diesel::query_builder::bind_collector::RawBytesBindCollector;
+    use std::num::NonZeroU32;
+
+    /// Interval has a static oid, so the lookup is never consulted.
+    struct NoLookup;
+
+    impl PgMetadataLookup for NoLookup {
+        fn lookup_type(&mut self, _: &str, _: Option<&str>) -> PgTypeMetadata {
+            unreachable!("interval has a static oid")
+        }
+    }
+
+    fn encode<T: ToSql<sql_types::Interval, Pg>>(value: &T) -> Vec<u8> {
+        let mut collector = RawBytesBindCollector::<Pg>::new();
+        collector
+            .push_bound_value::<sql_types::Interval, _>(value, &mut NoLookup)
+            .unwrap();
+        collector.binds.pop().unwrap().unwrap()
+    }
+
+    fn decode<T: FromSql<sql_types::Interval, Pg>>(bytes: &[u8]) -> deserialize::Result<T> {
+        let oid = || NonZeroU32::new(1186).unwrap();
+        T::from_sql(PgValue::new(bytes, &oid))
+    }
+
+    #[test]
+    fn test_encodes_like_pg_interval() {
+        for interval in [
+            Interval::new(14, -3, 4_000_000),
+            Interval::INFINITY,
+            Interval::NEG_INFINITY,
+        ] {
+            let pg_interval =
+                PgInterval::new(interval.microseconds, interval.days, interval.months);
+            let bytes = encode(&interval);
+            assert_eq!(bytes, encode(&pg_interval));
+            assert_eq!(bytes, interval.to_binary());
+            assert_eq!(decode::<Interval>(&bytes).unwrap(), interval);
+            assert_eq!(decode::<PgInterval>(&bytes).unwrap(), pg_interval);
+        }
+    }
+
+    #[test]
+    fn test_as_expression() {
+        use diesel::IntoSql;
+        let query = diesel::select(Interval::new(1, 2, 3).into_sql::<sql_types::Interval>());
+        assert_eq!(
+            diesel::debug_query::<Pg, _>(&query).to_string(),
+            "SELECT $1 -- binds: [Interval { months: 1, days: 2, microseconds: 3 }]"
+        );
+    }
+
+    #[test]
+    fn test_from_sql_wrong_length() {
+        assert!(decode::<Interval>(&[0; 12]).is_err());
+    }
+}
@@ -1,53 +1,71 @@
-use crate::{Interval, interval_norm::IntervalNorm};
+use super::conversion_error::ConversionError;
+use crate::pg_interval::{MICROS_PER_SECOND, NANOS_PER_MICRO};
+use crate::{DurationPolicy, Interval, RoundingMode};
 use chrono::Duration;
 
-const NANOS_PER_SEC: i64 = 1_000_000_000;
-const NANOS_PER_MICRO: i64 = 1000;
-
+// `Interval::age` and `Interval::between` for the chrono timestamps are in
+// `pg_interval_age`, through the `IntervalTimestamp` impls in `naive_date_time`.
 impl Interval {
     /// Tries to convert from the `Duration` type to a `Interval`. Will
     /// return `None` on a overflow. This is a lossy conversion in that
     /// any units smaller than a microsecond will be lost.
     pub fn from_duration(duration: Duration) -> Option<Interval> {
-        let mut days = duration.num_days();
-        let mut new_dur = duration - Duration::days(days);
-        let mut hours = duration.num_hours();
-        new_dur -= Duration::hours(hours);
-        let minutes = new_dur.num_minutes();
-        new_dur -= Duration::minutes(minutes);
-        let nano_secs = new_dur.num_nanoseconds()?;
-        if days > (i32::MAX as i64) {
-            let overflow_days = days - (i32::MAX as i64);
-            let added_hours = overflow_days.checked_mul(24)?;
-            hours = hours.checked_add(added_hours)?;
-            days -= overflow_days;
-        }
-        let (seconds, remaining_nano) = reduce_by_units(nano_secs, NANOS_PER_SEC);
-        // We have to discard any remaining nanoseconds
-        let (microseconds, _remaining_nano) = reduce_by_units(remaining_nano, NANOS_PER_MICRO);
-        let norm_interval = IntervalNorm {
-            years: 0,
-            months: 0,
-            days: days as i32,
-            hours,
-            minutes,
-            seconds,
-            microseconds,
-        };
-        norm_interval.try_into_interval().ok()
+        Interval::from_chrono_duration(duration, RoundingMode::Truncate).ok()
+    }
+
+    /// Converts the `Duration` into an interval the same way postgres represents the
+    /// difference between two timestamps, with whole 24 hour periods as days and the
+    /// rest as time. Any units smaller than a microsecond are rounded using the
+    /// rounding mode. Days beyond the range of the interval's days are kept in the
+    /// time part, and an error is returned if they don't fit there either.
+    pub fn from_chrono_duration(
+        duration: Duration,
+        rounding: RoundingMode,
+    ) -> Result<Interval, ConversionError> {
+        let micros = duration.num_seconds() as i128 * MICROS_PER_SECOND as i128
+            + rounding.div_thousand(duration.subsec_nanos() as i64) as i128;
+        Interval::from_duration_micros(micros)
+    }
+
+    /// Converts the interval into a `Duration`, using the policy to turn any months
+    /// and days into an absolute amount of time. Will return an error if the policy
+    /// rejects the interval, the interval is infinite or the result doesn't fit in a
+    /// `Duration`.
+    pub fn to_chrono_duration(&self, policy: DurationPolicy) -> Result<Duration, ConversionError> {
+        let micros = self.total_micros(policy)?;
+        let secs = micros.div_euclid(MICROS_PER_SECOND as i128);
+        let nanos = micros.rem_euclid(MICROS_PER_SECOND as i128) as u32 * NANOS_PER_MICRO as u32;
+        i64::try_from(secs)
+            .ok()
+            .and_then(|secs| Duration::new(secs, nanos))
+            .ok_or_else(|| {
+                ConversionError::from_out_of_range("Interval exceeds the duration range.")
+            })
+    }
+}
+
+impl TryFrom<Duration> for Interval {
+    type Error = ConversionError;
+    /// Truncates any units smaller than a microsecond, use
+    /// `Interval::from_chrono_duration` to round them instead.
+    fn try_from(duration: Duration) -> Result<Interval, ConversionError> {
+        Interval::from_chrono_duration(duration, RoundingMode::Truncate)
     }
 }
 
-fn reduce_by_units(nano_secs: i64, unit: i64) -> (i64, i64) {
-    let new_time_unit = (nano_secs - (nano_secs % unit)) / unit;
-    let remaining_nano = nano_secs - (new_time_unit * unit);
-    (new_time_unit, remaining_nano)
+impl TryFrom<Interval> for Duration {
+    type Error = ConversionError;
+    /// Only intervals without months or days can be converted, use
+    /// `Interval::to_chrono_duration` to choose how they are converted.
+    fn try_from(interval: Interval) -> Result<Duration, ConversionError> {
+        interval.to_chrono_duration(DurationPolicy::Reject)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Duration;
+    use crate::pg_interval::MICROS_PER_DAY;
 
     #[test]
     fn can_convert_small_amount_of_days() {
@@ -76,4 +94,115 @@ mod tests {
         let interval = Interval::from_duration(dur);
         assert_eq!(interval, Some(Interval::new(0, 0, 1)))
     }
+
+    #[test]
+    fn overflow_days_move_into_time() {
+        let dur = Duration::days(i32::MAX as i64 + 10) + Duration::hours(3);
+        let interval = Interval::from_duration(dur);
+        let micros = 10 * MICROS_PER_DAY + 3 * 3_600_000_000;
+        assert_eq!(interval, Some(Interval::new(0, i32::MAX, micros)));
+    }
+
+    #[test]
+    fn negative_overflow_days_move_into_time() {
+        let dur = Duration::days(i32::MIN as i64 - 10) - Duration::hours(3);
+        let interval = Interval::from_duration(dur);
+        let micros = 10 * MICROS_PER_DAY + 3 * 3_600_000_000;
+        assert_eq!(interval, Some(Interval::new(0, i32::MIN, -micros)));
+        let dur = Duration::days(-100000000000);
+        assert_eq!(Interval::from_duration(dur), None);
+    }
+
+    #[test]
+    fn can_convert_negative_duration() {
+        let dur = -(Duration::days(2) + Duration::minutes(3));
+        let interval = Interval::try_from(dur);
+        assert_eq!(interval, Ok(Interval::new(0, -2, -180_000_000)));
+    }
+
+    #[test]
+    fn rounds_sub_microseconds() {
+        let dur = Duration::nanoseconds(2_500);
+        assert_eq!(
+            Interval::from_chrono_duration(dur, RoundingMode::Truncate),
+            Ok(Interval::new(0, 0, 2))
+        );
+        assert_eq!(
+            Interval::from_chrono_duration(dur, RoundingMode::HalfEven),
+            Ok(Interval::new(0, 0, 2))
+        );
+        assert_eq!(
+            Interval::from_chrono_duration(dur, RoundingMode::HalfUp),
+            Ok(Interval::new(0, 0, 3))
+        );
+        let dur = Duration::nanoseconds(-3_500);
+        assert_eq!(
+            Interval::from_chrono_duration(dur, RoundingMode::Truncate),
+            Ok(Interval::new(0, 0, -3))
+        );
+        assert_eq!(
+            Interval::from_chrono_duration(dur, RoundingMode::HalfEven),
+            Ok(Interval::new(0, 0, -4))
+        );
+        assert_eq!(
+            Interval::from_chrono_duration(dur, RoundingMode::HalfUp),
+            Ok(Interval::new(0, 0, -4))
+        );
+        let dur = Duration::nanoseconds(1_999_999_501);
+        assert_eq!(
+            Interval::from_chrono_duration(dur, RoundingMode::HalfEven),
+            Ok(Interval::new(0, 0, 2_000_000))
+        );
+    }
+
+    #[test]
+    fn from_chrono_duration_out_of_range() {
+        assert!(matches!(
+            Interval::try_from(Duration::MAX),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn to_chrono_duration() {
+        let interval = Interval::new(0, 0, -90_000_001);
+        assert_eq!(
+            Duration::try_from(interval),
+            Ok(Duration::microseconds(-90_000_001))
+        );
+        assert!(matches!(
+            Duration::try_from(Interval::new(0, 1, 0)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+        // interval '1 mon -1 day'
+        let interval = Interval::new(1, -1, 0);
+        assert_eq!(
+            interval.to_chrono_duration(DurationPolicy::ThirtyDayMonths),
+            Ok(Duration::days(29))
+        );
+        // extract(epoch from interval '-1 year') = -31557600
+        let interval = Interval::new(-12, 0, 0);
+        assert_eq!(
+            interval.to_chrono_duration(DurationPolicy::JulianYears),
+            Ok(Duration::seconds(-31_557_600))
+        );
+    }
+
+    #[test]
+    fn to_chrono_duration_extremes() {
+        // about 180 million years, well within the range of a `Duration`.
+        let micros = Interval::MAX.cmp_value();
+        let expected = Duration::seconds((micros / 1_000_000) as i64)
+            + Duration::microseconds((micros % 1_000_000) as i64);
+        assert_eq!(
+            Interval::MAX.to_chrono_duration(DurationPolicy::ThirtyDayMonths),
+            Ok(expected)
+        );
+        let duration = Interval::MIN.to_chrono_duration(DurationPolicy::JulianYears);
+        assert!(duration.unwrap() < -expected);
+        assert!(matches!(
+            Interval::NEG_INFINITY.to_chrono_duration(DurationPolicy::Reject),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
 }
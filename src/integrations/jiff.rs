@@ -0,0 +1,348 @@
+use super::conversion_error::ConversionError;
+use crate::interval_arithmetic::{IntervalArithmetic, impl_interval_ops};
+use crate::pg_interval::{
+    DAYS_PER_WEEK, MICROS_PER_HOUR, MICROS_PER_MILLI, MICROS_PER_MINUTE, MICROS_PER_SECOND,
+    MONTHS_PER_YEAR, NANOS_PER_MICRO,
+};
+use crate::{Interval, IntervalTimestamp, RoundingMode};
+use jiff::civil::{Date, DateTime, Time};
+use jiff::tz::TimeZone;
+use jiff::{SignedDuration, Span, Zoned};
+
+impl Interval {
+    /// Converts the `Span` into an interval, with the years and months becoming
+    /// months, the weeks and days becoming days and the rest becoming time. Any
+    /// units smaller than a microsecond are rounded using the rounding mode.
+    pub fn from_jiff_span(span: Span, rounding: RoundingMode) -> Result<Interval, ConversionError> {
+        // the span's units are small enough for these not to overflow.
+        let months = span.get_years() as i32 * MONTHS_PER_YEAR + span.get_months();
+        let days = span.get_weeks() * DAYS_PER_WEEK + span.get_days();
+        let micros = span.get_hours() as i128 * MICROS_PER_HOUR as i128
+            + span.get_minutes() as i128 * MICROS_PER_MINUTE as i128
+            + span.get_seconds() as i128 * MICROS_PER_SECOND as i128
+            + span.get_milliseconds() as i128 * MICROS_PER_MILLI as i128
+            + span.get_microseconds() as i128
+            + rounding.div_thousand(span.get_nanoseconds()) as i128;
+        i64::try_from(micros)
+            .ok()
+            .map(|micros| Interval::new(months, days, micros))
+            .ok_or_else(|| ConversionError::from_out_of_range("Span exceeds the interval range."))
+    }
+}
+
+impl TryFrom<Span> for Interval {
+    type Error = ConversionError;
+    /// Truncates any units smaller than a microsecond, use
+    /// `Interval::from_jiff_span` to round them instead.
+    fn try_from(span: Span) -> Result<Interval, ConversionError> {
+        Interval::from_jiff_span(span, RoundingMode::Truncate)
+    }
+}
+
+impl TryFrom<Interval> for Span {
+    type Error = ConversionError;
+    /// Keeps the months, days and time separate like the interval does, splitting
+    /// them into the units postgres displays. A span has a single sign, so an
+    /// interval that mixes positive and negative parts can't be converted, and
+    /// each of the span's units has a smaller range than the interval.
+    fn try_from(interval: Interval) -> Result<Span, ConversionError> {
+        if interval.is_infinite() {
+            return Err(ConversionError::from_out_of_range(
+                "Infinite intervals can not be converted to a span.",
+            ));
+        }
+        let signs = [
+            interval.months.signum() as i64,
+            interval.days.signum() as i64,
+            interval.microseconds.signum(),
+        ];
+        if signs.contains(&1) && signs.contains(&-1) {
+            return Err(ConversionError::from_unrepresentable(
+                "Intervals with mixed signs can not be converted to a span.",
+            ));
+        }
+        let micros = interval.microseconds;
+        Span::new()
+            .try_years(interval.months / MONTHS_PER_YEAR)
+            .and_then(|span| span.try_months(interval.months % MONTHS_PER_YEAR))
+            .and_then(|span| span.try_days(interval.days))
+            .and_then(|span| span.try_hours(micros / MICROS_PER_HOUR))
+            .and_then(|span| span.try_minutes(micros % MICROS_PER_HOUR / MICROS_PER_MINUTE))
+            .and_then(|span| span.try_seconds(micros % MICROS_PER_MINUTE / MICROS_PER_SECOND))
+            .and_then(|span| span.try_microseconds(micros % MICROS_PER_SECOND))
+            .map_err(|_| ConversionError::from_out_of_range("Interval exceeds the span range."))
+    }
+}
+
+impl IntervalArithmetic for DateTime {
+    type Output = DateTime;
+
+    fn checked_add_interval(self, interval: Interval) -> Option<DateTime> {
+        if interval.is_infinite() {
+            return None;
+        }
+        let months = Span::new().try_months(interval.months).ok()?;
+        let days = Span::new().try_days(interval.days).ok()?;
+        self.checked_add(months)
+            .ok()?
+            .checked_add(days)
+            .ok()?
+            .checked_add(SignedDuration::from_micros(interval.microseconds))
+            .ok()
+    }
+}
+
+impl IntervalArithmetic for Date {
+    /// Like postgres adding an interval to a date produces a timestamp.
+    type Output = DateTime;
+
+    fn checked_add_interval(self, interval: Interval) -> Option<DateTime> {
+        self.to_datetime(Time::MIN).checked_add_interval(interval)
+    }
+
+    fn checked_sub_interval(self, interval: Interval) -> Option<DateTime> {
+        self.to_datetime(Time::MIN).checked_sub_interval(interval)
+    }
+}
+
+impl IntervalArithmetic for Time {
+    type Output = Time;
+
+    fn checked_add_interval(self, interval: Interval) -> Option<Time> {
+        if interval.is_infinite() {
+            return None;
+        }
+        Some(self.wrapping_add(SignedDuration::from_micros(interval.microseconds)))
+    }
+
+    fn checked_sub_interval(self, interval: Interval) -> Option<Time> {
+        if interval.is_infinite() {
+            return None;
+        }
+        Some(self.wrapping_sub(SignedDuration::from_micros(interval.microseconds)))
+    }
+}
+
+impl IntervalArithmetic for Zoned {
+    type Output = Zoned;
+
+    /// The months and days are added to the local wall clock time while the
+    /// time part is added in absolute time, like postgres does for `timestamptz`.
+    /// So adding `1 day` keeps the same local time across a daylight saving
+    /// transition while adding `24 hours` does not.
+    fn checked_add_interval(self, interval: Interval) -> Option<Zoned> {
+        if interval.is_infinite() {
+            return None;
+        }
+        let mut timestamp = self;
+        if interval.months != 0 {
+            timestamp = add_to_local(timestamp, Interval::new(interval.months, 0, 0))?;
+        }
+        if interval.days != 0 {
+            timestamp = add_to_local(timestamp, Interval::new(0, interval.days, 0))?;
+        }
+        timestamp
+            .checked_add(SignedDuration::from_micros(interval.microseconds))
+            .ok()
+    }
+}
+
+/// Adds the interval to the local wall clock time of the timestamp. Like postgres
+/// a local time skipped by a spring forward transition uses the offset from before
+/// the transition, and a local time repeated by a fall back transition uses the
+/// offset from after the transition.
+fn add_to_local(timestamp: Zoned, interval: Interval) -> Option<Zoned> {
+    let local = timestamp.datetime().checked_add_interval(interval)?;
+    timestamp.time_zone().to_ambiguous_zoned(local).later().ok()
+}
+
+impl IntervalTimestamp for DateTime {
+    fn unix_micros(self) -> Option<i64> {
+        let nanos = TimeZone::UTC.to_timestamp(self).ok()?.as_nanosecond();
+        i64::try_from(nanos.div_euclid(NANOS_PER_MICRO as i128)).ok()
+    }
+}
+
+impl IntervalTimestamp for Date {
+    fn unix_micros(self) -> Option<i64> {
+        self.to_datetime(Time::MIN).unix_micros()
+    }
+}
+
+impl_interval_ops!(DateTime, Date, Zoned);
+impl_interval_ops!(time: Time);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval_arithmetic::test_cases;
+
+    const HOUR: i64 = 3_600_000_000;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        jiff::civil::date(year as i16, month as i8, day as i8)
+    }
+
+    fn timestamp(year: i32, month: u32, day: u32, hour: u32) -> DateTime {
+        date(year, month, day).at(hour as i8, 0, 0, 0)
+    }
+
+    fn time(hour: u32, min: u32) -> Time {
+        jiff::civil::time(hour as i8, min as i8, 0, 0)
+    }
+
+    fn new_york() -> TimeZone {
+        TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap()
+    }
+
+    fn zoned(year: i32, month: u32, day: u32, hour: i8, min: i8) -> Zoned {
+        date(year, month, day)
+            .at(hour, min, 0, 0)
+            .to_zoned(new_york())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_to_span() {
+        // interval '1 year 2 mons 3 days 04:05:06.000007'
+        let interval = Interval::new(14, 3, 4 * HOUR + 5 * 60_000_000 + 6_000_007);
+        let span = Span::try_from(interval).unwrap();
+        assert_eq!(span.get_years(), 1);
+        assert_eq!(span.get_months(), 2);
+        assert_eq!(span.get_days(), 3);
+        assert_eq!(span.get_hours(), 4);
+        assert_eq!(span.get_minutes(), 5);
+        assert_eq!(span.get_seconds(), 6);
+        assert_eq!(span.get_microseconds(), 7);
+        assert_eq!(Interval::try_from(span), Ok(interval));
+    }
+
+    #[test]
+    fn test_to_negative_span() {
+        let interval = Interval::new(-14, 0, -90_000_000);
+        let span = Span::try_from(interval).unwrap();
+        assert_eq!(span.signum(), -1);
+        assert_eq!(span.get_years(), -1);
+        assert_eq!(span.get_months(), -2);
+        assert_eq!(span.get_minutes(), -1);
+        assert_eq!(span.get_seconds(), -30);
+        assert_eq!(Interval::try_from(span), Ok(interval));
+    }
+
+    #[test]
+    fn test_to_span_errors() {
+        assert!(matches!(
+            Span::try_from(Interval::new(1, -1, 0)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+        assert!(matches!(
+            Span::try_from(Interval::new(0, 1, -HOUR)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+        assert!(matches!(
+            Span::try_from(Interval::new(i32::MAX, 0, 0)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            Span::try_from(Interval::new(0, i32::MIN, 0)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            Span::try_from(Interval::INFINITY),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            Span::try_from(Interval::new(0, 0, i64::MAX - 1)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_span_folds_weeks() {
+        let span = Span::new().years(2).months(3).weeks(2).days(1).hours(25);
+        assert_eq!(
+            Interval::try_from(span),
+            Ok(Interval::new(27, 15, 25 * HOUR))
+        );
+        let span = Span::new().minutes(-1).milliseconds(-500);
+        assert_eq!(
+            Interval::try_from(span),
+            Ok(Interval::new(0, 0, -60_500_000))
+        );
+    }
+
+    #[test]
+    fn test_from_span_rounds() {
+        let span = Span::new().microseconds(2).nanoseconds(500);
+        assert_eq!(
+            Interval::from_jiff_span(span, RoundingMode::Truncate),
+            Ok(Interval::new(0, 0, 2))
+        );
+        assert_eq!(
+            Interval::from_jiff_span(span, RoundingMode::HalfEven),
+            Ok(Interval::new(0, 0, 2))
+        );
+        assert_eq!(
+            Interval::from_jiff_span(span, RoundingMode::HalfUp),
+            Ok(Interval::new(0, 0, 3))
+        );
+        let span = Span::new().nanoseconds(-1_500);
+        assert_eq!(
+            Interval::from_jiff_span(span, RoundingMode::HalfEven),
+            Ok(Interval::new(0, 0, -2))
+        );
+    }
+
+    #[test]
+    fn test_timestamp_arithmetic() {
+        test_cases::check_timestamp_arithmetic(date, timestamp);
+    }
+
+    #[test]
+    fn test_time_arithmetic() {
+        test_cases::check_time_arithmetic(time);
+    }
+
+    #[test]
+    fn test_age() {
+        test_cases::check_age(timestamp);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let interval = Interval::new(0, 1, 0);
+        assert_eq!(interval.checked_add_to(DateTime::MAX), None);
+        assert_eq!(interval.checked_sub_from(DateTime::MIN), None);
+    }
+
+    #[test]
+    fn test_add_day_keeps_wall_clock_across_dst() {
+        // set timezone = 'America/New_York';
+        // timestamptz '2024-03-09 12:00' + interval '1 day' = '2024-03-10 12:00:00-04'
+        let result = zoned(2024, 3, 9, 12, 0) + Interval::new(0, 1, 0);
+        assert_eq!(result, zoned(2024, 3, 10, 12, 0));
+        // timestamptz '2024-03-09 12:00' + interval '24 hours' = '2024-03-10 13:00:00-04'
+        let result = zoned(2024, 3, 9, 12, 0) + Interval::new(0, 0, 24 * HOUR);
+        assert_eq!(result, zoned(2024, 3, 10, 13, 0));
+    }
+
+    #[test]
+    fn test_add_into_dst_gap_and_fold() {
+        // timestamptz '2024-03-09 02:30' + interval '1 day' = '2024-03-10 03:30:00-04'
+        let result = zoned(2024, 3, 9, 2, 30) + Interval::new(0, 1, 0);
+        assert_eq!(result, zoned(2024, 3, 10, 3, 30));
+        // timestamptz '2024-11-02 01:30' + interval '1 day' = '2024-11-03 01:30:00-05'
+        let result = zoned(2024, 11, 2, 1, 30) + Interval::new(0, 1, 0);
+        assert_eq!(result.datetime(), date(2024, 11, 3).at(1, 30, 0, 0));
+        assert_eq!(result.offset(), jiff::tz::offset(-5));
+    }
+
+    #[test]
+    fn test_zoned_infinite_interval() {
+        assert_eq!(
+            Interval::INFINITY.checked_add_to(zoned(2024, 1, 1, 0, 0)),
+            None
+        );
+        assert_eq!(Interval::INFINITY.checked_add_to(time(10, 0)), None);
+    }
+}
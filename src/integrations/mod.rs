@@ -1,2 +1,33 @@
+#[cfg(feature = "apache-avro")]
+mod apache_avro;
+#[cfg(feature = "arrow")]
+mod arrow;
+mod avro_duration;
+#[cfg(any(feature = "postgres", feature = "sqlx", feature = "diesel"))]
+mod binary;
+pub mod conversion_error;
+#[cfg(feature = "chrono")]
+mod date_time;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "chrono")]
 mod duration;
+#[cfg(feature = "jiff")]
+mod jiff;
+#[cfg(feature = "chrono")]
+mod naive_date_time;
+#[cfg(feature = "prost")]
+pub mod prost;
+#[cfg(feature = "postgres")]
 mod rust_postgres;
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+#[cfg(feature = "sea-query")]
+mod sea_query;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+mod std_duration;
+#[cfg(feature = "time")]
+mod time;
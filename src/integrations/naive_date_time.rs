@@ -0,0 +1,245 @@
+use super::conversion_error::ConversionError;
+use crate::interval_arithmetic::{IntervalArithmetic, impl_interval_ops};
+use crate::{Interval, IntervalTimestamp};
+use chrono::{Days, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+
+impl IntervalArithmetic for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    fn checked_add_interval(self, interval: Interval) -> Option<NaiveDateTime> {
+        if interval.is_infinite() {
+            return None;
+        }
+        let timestamp = if interval.months < 0 {
+            self.checked_sub_months(Months::new(interval.months.unsigned_abs()))?
+        } else {
+            self.checked_add_months(Months::new(interval.months as u32))?
+        };
+        timestamp
+            .checked_add_signed(TimeDelta::days(interval.days as i64))?
+            .checked_add_signed(TimeDelta::microseconds(interval.microseconds))
+    }
+}
+
+impl IntervalArithmetic for NaiveDate {
+    /// Like postgres adding an interval to a date produces a timestamp.
+    type Output = NaiveDateTime;
+
+    fn checked_add_interval(self, interval: Interval) -> Option<NaiveDateTime> {
+        self.and_time(NaiveTime::MIN).checked_add_interval(interval)
+    }
+
+    fn checked_sub_interval(self, interval: Interval) -> Option<NaiveDateTime> {
+        self.and_time(NaiveTime::MIN).checked_sub_interval(interval)
+    }
+}
+
+impl IntervalArithmetic for NaiveTime {
+    type Output = NaiveTime;
+
+    fn checked_add_interval(self, interval: Interval) -> Option<NaiveTime> {
+        if interval.is_infinite() {
+            return None;
+        }
+        let (time, _) = self.overflowing_add_signed(TimeDelta::microseconds(interval.microseconds));
+        Some(time)
+    }
+
+    fn checked_sub_interval(self, interval: Interval) -> Option<NaiveTime> {
+        if interval.is_infinite() {
+            return None;
+        }
+        let (time, _) = self.overflowing_sub_signed(TimeDelta::microseconds(interval.microseconds));
+        Some(time)
+    }
+}
+
+impl IntervalTimestamp for NaiveDateTime {
+    /// The microseconds of every `NaiveDateTime` fit in an `i64`.
+    fn unix_micros(self) -> Option<i64> {
+        Some(self.and_utc().timestamp_micros())
+    }
+}
+
+impl IntervalTimestamp for NaiveDate {
+    fn unix_micros(self) -> Option<i64> {
+        self.and_time(NaiveTime::MIN).unix_micros()
+    }
+}
+
+impl_interval_ops!(NaiveDateTime, NaiveDate);
+impl_interval_ops!(time: NaiveTime);
+
+impl TryFrom<Months> for Interval {
+    type Error = ConversionError;
+    fn try_from(months: Months) -> Result<Interval, ConversionError> {
+        let months = i32::try_from(months.as_u32())
+            .map_err(|_| ConversionError::from_out_of_range("Months exceed the interval range."))?;
+        Ok(Interval::new(months, 0, 0))
+    }
+}
+
+impl TryFrom<Interval> for Months {
+    type Error = ConversionError;
+    fn try_from(interval: Interval) -> Result<Months, ConversionError> {
+        if interval.days != 0 || interval.microseconds != 0 {
+            Err(ConversionError::from_unrepresentable(
+                "Only intervals with just months can be converted to months.",
+            ))
+        } else if interval.months < 0 {
+            Err(ConversionError::from_negative(
+                "Negative intervals can not be converted to months.",
+            ))
+        } else {
+            Ok(Months::new(interval.months as u32))
+        }
+    }
+}
+
+impl TryFrom<Days> for Interval {
+    type Error = ConversionError;
+    fn try_from(days: Days) -> Result<Interval, ConversionError> {
+        if days > Days::new(i32::MAX as u64) {
+            return Err(ConversionError::from_out_of_range(
+                "Days exceed the interval range.",
+            ));
+        }
+        // `Days` doesn't expose its count, so it is found bit by bit by comparing.
+        let count = (0..31).rev().fold(0, |count, bit| {
+            let candidate = count | 1 << bit;
+            if Days::new(candidate as u64) <= days {
+                candidate
+            } else {
+                count
+            }
+        });
+        Ok(Interval::new(0, count, 0))
+    }
+}
+
+impl TryFrom<Interval> for Days {
+    type Error = ConversionError;
+    fn try_from(interval: Interval) -> Result<Days, ConversionError> {
+        if interval.months != 0 || interval.microseconds != 0 {
+            Err(ConversionError::from_unrepresentable(
+                "Only intervals with just days can be converted to days.",
+            ))
+        } else if interval.days < 0 {
+            Err(ConversionError::from_negative(
+                "Negative intervals can not be converted to days.",
+            ))
+        } else {
+            Ok(Days::new(interval.days as u64))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval_arithmetic::test_cases;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn timestamp(year: i32, month: u32, day: u32, hour: u32) -> NaiveDateTime {
+        date(year, month, day).and_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    fn time(hour: u32, min: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_timestamp_arithmetic() {
+        test_cases::check_timestamp_arithmetic(date, timestamp);
+    }
+
+    #[test]
+    fn test_time_arithmetic() {
+        test_cases::check_time_arithmetic(time);
+    }
+
+    #[test]
+    fn test_age() {
+        test_cases::check_age(timestamp);
+    }
+
+    #[test]
+    fn test_checked_add_to_out_of_range() {
+        let interval = Interval::new(0, 1, 0);
+        assert_eq!(interval.checked_add_to(NaiveDateTime::MAX), None);
+        assert_eq!(interval.checked_sub_from(NaiveDateTime::MIN), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add infinite interval to time")]
+    fn test_time_add_infinity_panics() {
+        let _ = time(10, 0) + Interval::INFINITY;
+    }
+
+    #[test]
+    #[should_panic(expected = "timestamp out of range")]
+    fn test_add_out_of_range_panics() {
+        let _ = NaiveDateTime::MAX + Interval::new(0, 1, 0);
+    }
+    #[test]
+    fn test_months_conversion() {
+        assert_eq!(
+            Interval::try_from(Months::new(14)),
+            Ok(Interval::new(14, 0, 0))
+        );
+        assert!(Interval::try_from(Months::new(u32::MAX)).is_err());
+        assert_eq!(
+            Months::try_from(Interval::new(14, 0, 0)),
+            Ok(Months::new(14))
+        );
+        assert!(matches!(
+            Months::try_from(Interval::new(-1, 0, 0)),
+            Err(ConversionError::Negative(_))
+        ));
+        assert!(matches!(
+            Months::try_from(Interval::new(1, 1, 0)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+    }
+
+    #[test]
+    fn test_days_conversion() {
+        assert_eq!(Interval::try_from(Days::new(3)), Ok(Interval::new(0, 3, 0)));
+        assert_eq!(Interval::try_from(Days::new(0)), Ok(Interval::ZERO));
+        assert_eq!(
+            Interval::try_from(Days::new(i32::MAX as u64)),
+            Ok(Interval::new(0, i32::MAX, 0))
+        );
+        assert!(matches!(
+            Interval::try_from(Days::new(i32::MAX as u64 + 1)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        let days = Days::new(123_456_789);
+        assert_eq!(Interval::try_from(days).and_then(Days::try_from), Ok(days));
+        assert_eq!(Days::try_from(Interval::new(0, 3, 0)), Ok(Days::new(3)));
+        assert!(matches!(
+            Days::try_from(Interval::new(0, -3, 0)),
+            Err(ConversionError::Negative(_))
+        ));
+        assert!(matches!(
+            Days::try_from(Interval::new(0, 3, 1)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+    }
+
+    #[test]
+    fn test_age_truncates_nanoseconds() {
+        let later = timestamp(2024, 1, 1, 0) + TimeDelta::nanoseconds(1_999);
+        let interval = Interval::age(later, timestamp(2024, 1, 1, 0));
+        assert_eq!(interval, Ok(Interval::new(0, 0, 1)))
+    }
+
+    #[test]
+    fn test_between_out_of_range() {
+        let interval = Interval::between(NaiveDateTime::MAX, NaiveDateTime::MIN);
+        assert!(matches!(interval, Err(ConversionError::OutOfRange(_))))
+    }
+}
@@ -0,0 +1,294 @@
+//! Conversions with the protobuf `google.protobuf.Duration` and a message keeping
+//! the parts of an interval separate.
+use super::conversion_error::ConversionError;
+use crate::pg_interval::{MICROS_PER_SECOND, NANOS_PER_MICRO};
+use crate::{DurationPolicy, Interval, RoundingMode};
+use prost_types::Duration;
+
+const NANOS_PER_SEC: i32 = 1_000_000_000;
+/// The largest number of seconds a `google.protobuf.Duration` may have, about
+/// 10,000 years.
+const MAX_DURATION_SECS: i64 = 315_576_000_000;
+
+/// The `pg_interval.Interval` message from `proto/pg_interval.proto`.
+pub mod proto {
+    /// A postgres interval with the months, days and microseconds kept separate,
+    /// the same way postgres stores them.
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+    pub struct Interval {
+        #[prost(int32, tag = "1")]
+        pub months: i32,
+        #[prost(int32, tag = "2")]
+        pub days: i32,
+        #[prost(int64, tag = "3")]
+        pub microseconds: i64,
+    }
+}
+
+impl Interval {
+    /// Converts the protobuf duration into an interval the same way postgres
+    /// represents the difference between two timestamps, with whole 24 hour periods
+    /// as days and the rest as time. Any units smaller than a microsecond are rounded
+    /// using the rounding mode. Will return an error if the duration is not valid,
+    /// i.e. its seconds are out of range or its nanos are out of range or have a
+    /// different sign than the seconds.
+    pub fn from_prost_duration(
+        duration: Duration,
+        rounding: RoundingMode,
+    ) -> Result<Interval, ConversionError> {
+        if !(-MAX_DURATION_SECS..=MAX_DURATION_SECS).contains(&duration.seconds)
+            || duration.nanos <= -NANOS_PER_SEC
+            || duration.nanos >= NANOS_PER_SEC
+        {
+            return Err(ConversionError::from_out_of_range(
+                "Duration seconds or nanos are out of range.",
+            ));
+        }
+        if duration.seconds.signum() * (duration.nanos.signum() as i64) < 0 {
+            return Err(ConversionError::from_unrepresentable(
+                "Duration seconds and nanos must have the same sign.",
+            ));
+        }
+        let micros = duration.seconds as i128 * MICROS_PER_SECOND as i128
+            + rounding.div_thousand(duration.nanos as i64) as i128;
+        Interval::from_duration_micros(micros)
+    }
+
+    /// Converts the interval into a protobuf duration, using the policy to turn any
+    /// months and days into an absolute amount of time. Will return an error if the
+    /// policy rejects the interval, the interval is infinite or it exceeds the
+    /// duration's range of about 10,000 years.
+    pub fn to_prost_duration(&self, policy: DurationPolicy) -> Result<Duration, ConversionError> {
+        let micros = self.total_micros(policy)?;
+        // the seconds of the largest interval fit easily in an i64.
+        let seconds = (micros / MICROS_PER_SECOND as i128) as i64;
+        if !(-MAX_DURATION_SECS..=MAX_DURATION_SECS).contains(&seconds) {
+            return Err(ConversionError::from_out_of_range(
+                "Interval exceeds the range of a protobuf duration.",
+            ));
+        }
+        Ok(Duration {
+            seconds,
+            nanos: (micros % MICROS_PER_SECOND as i128) as i32 * NANOS_PER_MICRO as i32,
+        })
+    }
+}
+
+impl TryFrom<Duration> for Interval {
+    type Error = ConversionError;
+    /// Truncates any units smaller than a microsecond, use
+    /// `Interval::from_prost_duration` to round them instead.
+    fn try_from(duration: Duration) -> Result<Interval, ConversionError> {
+        Interval::from_prost_duration(duration, RoundingMode::Truncate)
+    }
+}
+
+impl TryFrom<Interval> for Duration {
+    type Error = ConversionError;
+    /// Only intervals without months or days can be converted, use
+    /// `Interval::to_prost_duration` to choose how they are converted.
+    fn try_from(interval: Interval) -> Result<Duration, ConversionError> {
+        interval.to_prost_duration(DurationPolicy::Reject)
+    }
+}
+
+impl From<proto::Interval> for Interval {
+    fn from(interval: proto::Interval) -> Interval {
+        Interval::new(interval.months, interval.days, interval.microseconds)
+    }
+}
+
+impl From<Interval> for proto::Interval {
+    fn from(interval: Interval) -> proto::Interval {
+        proto::Interval {
+            months: interval.months,
+            days: interval.days,
+            microseconds: interval.microseconds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+    use protobuf::reflect::{FileDescriptor, MessageDescriptor, ReflectValueBox};
+
+    const DAY_SECS: i64 = 86_400;
+
+    fn duration(seconds: i64, nanos: i32) -> Duration {
+        Duration { seconds, nanos }
+    }
+
+    #[test]
+    fn test_from_duration() {
+        let interval = Interval::try_from(duration(DAY_SECS + 4, 5_000)).unwrap();
+        assert_eq!(interval, Interval::new(0, 1, 4_000_005));
+        let interval = Interval::try_from(duration(-DAY_SECS - 4, -5_000)).unwrap();
+        assert_eq!(interval, Interval::new(0, -1, -4_000_005));
+        let interval = Interval::try_from(duration(0, -999_999_999)).unwrap();
+        assert_eq!(interval, Interval::new(0, 0, -999_999));
+    }
+
+    #[test]
+    fn test_from_duration_rounding() {
+        let value = duration(1, 2_500);
+        assert_eq!(
+            Interval::from_prost_duration(value, RoundingMode::Truncate),
+            Ok(Interval::new(0, 0, 1_000_002))
+        );
+        assert_eq!(
+            Interval::from_prost_duration(value, RoundingMode::HalfEven),
+            Ok(Interval::new(0, 0, 1_000_002))
+        );
+        assert_eq!(
+            Interval::from_prost_duration(value, RoundingMode::HalfUp),
+            Ok(Interval::new(0, 0, 1_000_003))
+        );
+    }
+
+    #[test]
+    fn test_from_duration_limits() {
+        let interval = Interval::try_from(duration(MAX_DURATION_SECS, 999_999_999)).unwrap();
+        assert_eq!(interval.days, (MAX_DURATION_SECS / DAY_SECS) as i32);
+        let interval = Interval::try_from(duration(-MAX_DURATION_SECS, -999_999_999)).unwrap();
+        assert_eq!(interval.days, (-MAX_DURATION_SECS / DAY_SECS) as i32);
+    }
+
+    #[test]
+    fn test_from_invalid_duration() {
+        for value in [
+            duration(MAX_DURATION_SECS + 1, 0),
+            duration(-MAX_DURATION_SECS - 1, 0),
+            duration(0, NANOS_PER_SEC),
+            duration(0, -NANOS_PER_SEC),
+        ] {
+            assert!(matches!(
+                Interval::try_from(value),
+                Err(ConversionError::OutOfRange(_))
+            ));
+        }
+        for value in [duration(1, -1), duration(-1, 1)] {
+            assert!(matches!(
+                Interval::try_from(value),
+                Err(ConversionError::Unrepresentable(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_to_duration() {
+        let interval = Interval::new(0, 0, -4_000_005);
+        assert_eq!(Duration::try_from(interval), Ok(duration(-4, -5_000)));
+        let interval = Interval::new(0, 0, 999_999);
+        assert_eq!(Duration::try_from(interval), Ok(duration(0, 999_999_000)));
+    }
+
+    #[test]
+    fn test_to_duration_policy() {
+        let interval = Interval::new(1, 1, 1_000_000);
+        assert!(matches!(
+            Duration::try_from(interval),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+        assert_eq!(
+            interval.to_prost_duration(DurationPolicy::ThirtyDayMonths),
+            Ok(duration(31 * DAY_SECS + 1, 0))
+        );
+        let interval = Interval::new(12, 0, 0);
+        assert_eq!(
+            interval.to_prost_duration(DurationPolicy::JulianYears),
+            Ok(duration(365 * DAY_SECS + DAY_SECS / 4, 0))
+        );
+    }
+
+    #[test]
+    fn test_to_duration_out_of_range() {
+        assert!(matches!(
+            Duration::try_from(Interval::new(0, 0, i64::MAX - 1)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            Interval::INFINITY.to_prost_duration(DurationPolicy::ThirtyDayMonths),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        let interval = Interval::new(0, 0, MAX_DURATION_SECS * 1_000_000);
+        assert_eq!(
+            Duration::try_from(interval),
+            Ok(duration(MAX_DURATION_SECS, 0))
+        );
+    }
+
+    #[test]
+    fn test_proto_interval() {
+        let interval = Interval::new(14, -3, 4_000_000);
+        let message = proto::Interval::from(interval);
+        let bytes = message.encode_to_vec();
+        assert_eq!(
+            bytes,
+            [
+                0x08, 0x0e, 0x10, 0xfd, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, 0x18,
+                0x80, 0x92, 0xf4, 0x01
+            ]
+        );
+        let decoded = proto::Interval::decode(bytes.as_slice()).unwrap();
+        assert_eq!(Interval::from(decoded), interval);
+    }
+
+    #[test]
+    fn test_proto_interval_limits() {
+        for interval in [Interval::INFINITY, Interval::NEG_INFINITY, Interval::ZERO] {
+            let bytes = proto::Interval::from(interval).encode_to_vec();
+            let decoded = proto::Interval::decode(bytes.as_slice()).unwrap();
+            assert_eq!(Interval::from(decoded), interval);
+        }
+        assert!(proto::Interval::default().encode_to_vec().is_empty());
+    }
+
+    /// The `Interval` message from `proto/pg_interval.proto`, parsed at runtime.
+    fn proto_file_message() -> MessageDescriptor {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/proto");
+        let parsed = protobuf_parse::Parser::new()
+            .pure()
+            .include(dir)
+            .input(format!("{}/pg_interval.proto", dir))
+            .parse_and_typecheck()
+            .unwrap();
+        let files = FileDescriptor::new_dynamic_fds(parsed.file_descriptors, &[]).unwrap();
+        assert_eq!(files[0].package(), "pg_interval");
+        files[0]
+            .message_by_package_relative_name("Interval")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_proto_file_matches() {
+        let message = proto_file_message();
+        // a struct literal so a new field on the message has to be added here.
+        let interval = proto::Interval {
+            months: -14,
+            days: 3,
+            microseconds: -4_000_000_007,
+        };
+        let expected = [
+            ("months", ReflectValueBox::I32(interval.months)),
+            ("days", ReflectValueBox::I32(interval.days)),
+            ("microseconds", ReflectValueBox::I64(interval.microseconds)),
+        ];
+        let bytes = interval.encode_to_vec();
+        let decoded = message.parse_from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.unknown_fields_dyn().iter().count(), 0);
+        assert_eq!(message.fields().count(), expected.len());
+        for field in message.fields() {
+            let (_, value) = expected
+                .iter()
+                .find(|(name, _)| *name == field.name())
+                .unwrap_or_else(|| panic!("field {} is missing", field.name()));
+            assert_eq!(
+                &field.get_singular_field_or_default(&*decoded).to_box(),
+                value
+            );
+        }
+        assert_eq!(decoded.write_to_bytes_dyn().unwrap(), bytes);
+    }
+}
@@ -1,18 +1,11 @@
 use crate::Interval;
-use bytes::{Buf, BufMut, BytesMut};
+use bytes::{BufMut, BytesMut};
 use postgres_types::{FromSql, IsNull, ToSql, Type, to_sql_checked};
 use std::error::Error;
 
 impl<'a> FromSql<'a> for Interval {
-    fn from_sql(_: &Type, mut raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
-        let microseconds = raw.get_i64();
-        let days = raw.get_i32();
-        let months = raw.get_i32();
-        Ok(Interval {
-            months,
-            days,
-            microseconds,
-        })
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Interval::from_binary(raw)?)
     }
 
     fn accepts(ty: &Type) -> bool {
@@ -22,9 +15,7 @@ impl<'a> FromSql<'a> for Interval {
 
 impl ToSql for Interval {
     fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
-        out.put_i64(self.microseconds);
-        out.put_i32(self.days);
-        out.put_i32(self.months);
+        out.put_slice(&self.to_binary());
         Ok(IsNull::No)
     }
 
@@ -34,3 +25,52 @@ impl ToSql for Interval {
 
     to_sql_checked!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(interval: Interval) -> (Vec<u8>, Interval) {
+        let mut out = BytesMut::new();
+        ToSql::to_sql(&interval, &Type::INTERVAL, &mut out).unwrap();
+        let decoded = <Interval as FromSql>::from_sql(&Type::INTERVAL, &out).unwrap();
+        (out.to_vec(), decoded)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let interval = Interval::new(14, -3, 4_000_000);
+        assert_eq!(round_trip(interval).1, interval);
+    }
+
+    #[test]
+    fn test_infinity_round_trip() {
+        // postgres 17 sends 'infinity' with every field at its maximum.
+        let (bytes, decoded) = round_trip(Interval::INFINITY);
+        assert_eq!(
+            bytes,
+            [
+                0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f, 0xff, 0xff, 0xff, 0x7f, 0xff,
+                0xff, 0xff
+            ]
+        );
+        assert_eq!(decoded, Interval::INFINITY);
+        assert!(!decoded.is_finite());
+    }
+
+    #[test]
+    fn test_from_sql_wrong_length() {
+        let result = <Interval as FromSql>::from_sql(&Type::INTERVAL, &[0; 12]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_neg_infinity_round_trip() {
+        let (bytes, decoded) = round_trip(Interval::NEG_INFINITY);
+        assert_eq!(
+            bytes,
+            [0x80, 0, 0, 0, 0, 0, 0, 0, 0x80, 0, 0, 0, 0x80, 0, 0, 0]
+        );
+        assert_eq!(decoded, Interval::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{Interval, ParseError};
+use sea_orm::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable};
+use sqlx::Row;
+
+/// Lets entity models declare `Interval` columns. Since SeaQuery binds an
+/// interval as text the column needs `save_as = "interval"` so postgres casts it
+/// when the model is saved.
+impl TryGetable for Interval {
+    /// Postgres rows are decoded from their interval value, any other rows, such as
+    /// SeaORM's proxy rows, are expected to hold the interval's text.
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Interval, TryGetError> {
+        if let Some(row) = res.try_as_pg_row() {
+            return row
+                .try_get::<Option<Interval>, _>(index.as_sqlx_postgres_index())
+                .map_err(|err| TryGetError::DbErr(DbErr::Type(err.to_string())))?
+                .ok_or_else(|| TryGetError::Null(format!("{index:?}")));
+        }
+        String::try_get_by(res, index)?
+            .parse()
+            .map_err(|err: ParseError| TryGetError::DbErr(DbErr::Type(err.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::entity::prelude::*;
+    use sea_orm::sea_query::Nullable;
+    use sea_orm::{DbBackend, FromQueryResult, ProxyRow, QueryTrait, Set};
+    use std::collections::BTreeMap;
+
+    mod job {
+        use crate::Interval;
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "job")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            #[sea_orm(save_as = "interval")]
+            pub timeout: Interval,
+            #[sea_orm(save_as = "interval")]
+            pub retry_after: Option<Interval>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    fn row(values: Vec<(&str, sea_orm::Value)>) -> QueryResult {
+        let values: BTreeMap<String, sea_orm::Value> = values
+            .into_iter()
+            .map(|(column, value)| (column.to_owned(), value))
+            .collect();
+        ProxyRow::new(values).into()
+    }
+
+    #[test]
+    fn test_try_get() {
+        let interval = Interval::new(14, -3, 4_000_000);
+        let res = row(vec![
+            ("timeout", interval.into()),
+            ("retry_after", Interval::null()),
+        ]);
+        assert_eq!(Interval::try_get(&res, "", "timeout").unwrap(), interval);
+        assert_eq!(
+            Option::<Interval>::try_get(&res, "", "retry_after").unwrap(),
+            None
+        );
+        assert!(matches!(
+            Interval::try_get(&res, "", "retry_after"),
+            Err(TryGetError::Null(_))
+        ));
+    }
+
+    #[test]
+    fn test_try_get_invalid() {
+        let res = row(vec![("timeout", "soon".into())]);
+        assert!(matches!(
+            Interval::try_get(&res, "", "timeout"),
+            Err(TryGetError::DbErr(DbErr::Type(_)))
+        ));
+    }
+
+    #[test]
+    fn test_model_from_row() {
+        let res = row(vec![
+            ("id", 1.into()),
+            ("timeout", Interval::new(0, 0, 90_000_000).into()),
+            ("retry_after", Some(Interval::new(0, 1, 0)).into()),
+        ]);
+        assert_eq!(
+            job::Model::from_query_result(&res, "").unwrap(),
+            job::Model {
+                id: 1,
+                timeout: Interval::new(0, 0, 90_000_000),
+                retry_after: Some(Interval::new(0, 1, 0)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_insert_casts_to_interval() {
+        let model = job::ActiveModel {
+            id: Set(1),
+            timeout: Set(Interval::new(0, 0, 90_000_000)),
+            retry_after: Set(None),
+        };
+        let insert = job::Entity::insert(model).build(DbBackend::Postgres);
+        assert_eq!(
+            insert.to_string(),
+            r#"INSERT INTO "job" ("id", "timeout", "retry_after") VALUES (1, CAST('00:01:30' AS interval), CAST(NULL AS interval))"#
+        );
+        assert_eq!(
+            insert.values.unwrap().0[1],
+            sea_orm::Value::from(Interval::new(0, 0, 90_000_000))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::Interval;
+use sea_query::{
+    Alias, ArrayType, ColumnType, Expr, Nullable, SimpleExpr, Value, ValueType, ValueTypeErr,
+};
+
+/// SeaQuery has no interval value, so an interval is carried as its text in
+/// postgres' `postgres` interval style. Postgres only sees the bound value as
+/// text, so use `Interval::to_sea_query_expr` to compare with or store into
+/// interval columns.
+impl From<Interval> for Value {
+    fn from(interval: Interval) -> Value {
+        Value::String(Some(Box::new(interval.to_postgres())))
+    }
+}
+
+impl Nullable for Interval {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl ValueType for Interval {
+    /// Accepts the text of an interval in any of the supported formats.
+    fn try_from(value: Value) -> Result<Interval, ValueTypeErr> {
+        match value {
+            Value::String(Some(text)) => text.parse::<Interval>().map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Interval".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Interval(None, None)
+    }
+}
+
+impl Interval {
+    /// Returns the interval as a bound value cast to `interval`. Unlike the plain
+    /// `Value`, which postgres only sees as text, the expression can be compared
+    /// with and stored into interval columns.
+    pub fn to_sea_query_expr(self) -> SimpleExpr {
+        Expr::val(self).cast_as(Alias::new("interval"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_query::{PostgresQueryBuilder, Query};
+
+    #[test]
+    fn test_value_round_trip() {
+        for interval in [
+            Interval::new(14, -3, 4_000_000),
+            Interval::ZERO,
+            Interval::INFINITY,
+            Interval::NEG_INFINITY,
+        ] {
+            let value = Value::from(interval);
+            assert_eq!(value, Value::String(Some(Box::new(interval.to_postgres()))));
+            assert_eq!(value.unwrap::<Interval>(), interval);
+        }
+    }
+
+    #[test]
+    fn test_value_from_other_formats() {
+        let value = Value::from("P1Y2M-3DT4S");
+        assert_eq!(value.unwrap::<Interval>(), Interval::new(14, -3, 4_000_000));
+    }
+
+    #[test]
+    fn test_value_invalid() {
+        assert!(<Interval as ValueType>::try_from(Value::from("soon")).is_err());
+        assert!(<Interval as ValueType>::try_from(Value::Int(Some(1))).is_err());
+        assert!(<Interval as ValueType>::try_from(Interval::null()).is_err());
+    }
+
+    #[test]
+    fn test_optional_value() {
+        let interval = Interval::new(1, 0, 0);
+        assert_eq!(Value::from(Some(interval)), Value::from(interval));
+        assert_eq!(Value::from(None::<Interval>), Value::String(None));
+        assert_eq!(
+            Value::from(Some(interval)).unwrap::<Option<Interval>>(),
+            Some(interval)
+        );
+        assert_eq!(Interval::null().unwrap::<Option<Interval>>(), None);
+    }
+
+    #[test]
+    fn test_column_type() {
+        assert_eq!(Interval::column_type(), ColumnType::Interval(None, None));
+        assert_eq!(Interval::array_type(), ArrayType::String);
+        assert_eq!(Interval::type_name(), "Interval");
+    }
+
+    #[test]
+    fn test_postgres_cast() {
+        let interval = Interval::new(14, -3, 4_000_000);
+        let query = Query::select()
+            .column(Alias::new("id"))
+            .from(Alias::new("job"))
+            .and_where(Expr::col(Alias::new("timeout")).lt(interval.to_sea_query_expr()))
+            .to_owned();
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            r#"SELECT "id" FROM "job" WHERE "timeout" < CAST('1 year 2 mons -3 days 00:00:04' AS interval)"#
+        );
+        let (sql, values) = query.build(PostgresQueryBuilder);
+        assert!(sql.ends_with(r#""timeout" < CAST($1 AS interval)"#));
+        assert_eq!(values.0, vec![Value::from(interval)]);
+    }
+
+    #[test]
+    fn test_postgres_infinity_cast() {
+        let query = Query::select()
+            .expr(Interval::NEG_INFINITY.to_sea_query_expr())
+            .to_owned();
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            "SELECT CAST('-infinity' AS interval)"
+        );
+    }
+}
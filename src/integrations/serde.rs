@@ -0,0 +1,258 @@
+//! Serde support for `Interval`.
+//!
+//! By default an interval is serialized as a string in postgres' default
+//! `postgres` interval style, e.g. `1 year 2 mons -3 days 04:05:06`, and only
+//! that style is accepted when deserializing. The modules in here can be used with
+//! `#[serde(with = "...")]` to pick another representation for a field.
+use crate::{Interval, ParseError};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Deserializes a string using the parser.
+struct StrVisitor {
+    parse: fn(&str) -> Result<Interval, ParseError>,
+    expecting: &'static str,
+}
+
+impl Visitor<'_> for StrVisitor {
+    type Value = Interval;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Interval, E> {
+        (self.parse)(value)
+            .map_err(|error| E::custom(format_args!("invalid interval {:?}: {}", value, error)))
+    }
+}
+
+impl Serialize for Interval {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        postgres::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Interval {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Interval, D::Error> {
+        postgres::deserialize(deserializer)
+    }
+}
+
+/// An interval as a string in postgres' `postgres` interval style, using
+/// `Interval::to_postgres` and `Interval::from_postgres`. This is the default.
+pub mod postgres {
+    use super::StrVisitor;
+    use crate::Interval;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(interval: &Interval, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&interval.to_postgres())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Interval, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            parse: Interval::from_postgres,
+            expecting: "a postgres style interval string",
+        })
+    }
+}
+
+/// An interval as an ISO 8601 duration string, using `Interval::to_iso_8601` and
+/// `Interval::from_iso`.
+pub mod iso_8601 {
+    use super::StrVisitor;
+    use crate::Interval;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(interval: &Interval, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&interval.to_iso_8601())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Interval, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            parse: Interval::from_iso,
+            expecting: "an iso 8601 interval string",
+        })
+    }
+}
+
+/// An interval as a string in postgres' `sql_standard` interval style, using
+/// `Interval::to_sql` and `Interval::from_sql`.
+pub mod sql {
+    use super::StrVisitor;
+    use crate::Interval;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(interval: &Interval, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&interval.to_sql())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Interval, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            parse: Interval::from_sql,
+            expecting: "a sql standard interval string",
+        })
+    }
+}
+
+/// An interval as a struct of the months, days and microseconds postgres stores,
+/// e.g. `{"months": 14, "days": -3, "microseconds": 14706000000}`.
+pub mod parts {
+    use crate::Interval;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Interval")]
+    struct Parts {
+        months: i32,
+        days: i32,
+        microseconds: i64,
+    }
+
+    pub fn serialize<S: Serializer>(interval: &Interval, serializer: S) -> Result<S::Ok, S::Error> {
+        Parts {
+            months: interval.months,
+            days: interval.days,
+            microseconds: interval.microseconds,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Interval, D::Error> {
+        let parts = Parts::deserialize(deserializer)?;
+        Ok(Interval::new(parts.months, parts.days, parts.microseconds))
+    }
+}
+
+/// Serializes an interval like the default, but deserializes a string in any of
+/// the supported formats, detecting the format the same way `Interval::from_str`
+/// does.
+pub mod lenient {
+    use super::StrVisitor;
+    use crate::Interval;
+    use serde::{Deserializer, Serializer};
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(interval: &Interval, serializer: S) -> Result<S::Ok, S::Error> {
+        super::postgres::serialize(interval, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Interval, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            parse: Interval::from_str,
+            expecting: "an interval string",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3_600_000_000;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Formats {
+        default: Interval,
+        #[serde(with = "iso_8601")]
+        iso: Interval,
+        #[serde(with = "sql")]
+        sql: Interval,
+        #[serde(with = "parts")]
+        parts: Interval,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Lenient {
+        #[serde(with = "lenient")]
+        interval: Interval,
+    }
+
+    fn intervals() -> Vec<Interval> {
+        vec![
+            Interval::ZERO,
+            Interval::new(14, 3, 4 * HOUR + 5_000_006),
+            Interval::new(-14, -3, -4 * HOUR - 5_000_006),
+            Interval::new(1, -1, 0),
+            Interval::new(-1, 1, -1),
+            Interval::new(-1, 0, 0),
+            Interval::new(0, -1, -1),
+            Interval::new(0, 0, -500_000),
+            Interval::MAX,
+            Interval::MIN,
+            Interval::new(i32::MAX, i32::MIN, i64::MAX),
+            Interval::INFINITY,
+            Interval::NEG_INFINITY,
+        ]
+    }
+
+    #[test]
+    fn test_serialize() {
+        let interval = Interval::new(14, -3, -4 * HOUR - 500_000);
+        let formats = Formats {
+            default: interval,
+            iso: interval,
+            sql: interval,
+            parts: interval,
+        };
+        let json = serde_json::to_value(&formats).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "default": "1 year 2 mons -3 days -04:00:00.500000",
+                "iso": "P1Y2M-3DT-4H-0.500000S",
+                "sql": "+1-2 -3 -4:00:00.500000",
+                "parts": {"months": 14, "days": -3, "microseconds": -14_400_500_000i64},
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for interval in intervals() {
+            let formats = Formats {
+                default: interval,
+                iso: interval,
+                sql: interval,
+                parts: interval,
+            };
+            let json = serde_json::to_string(&formats).unwrap();
+            let result: Formats = serde_json::from_str(&json).unwrap();
+            assert_eq!(result, formats, "{}", json);
+        }
+    }
+
+    #[test]
+    fn test_deserialize_infinity() {
+        let interval: Interval = serde_json::from_str("\"-infinity\"").unwrap();
+        assert_eq!(interval, Interval::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        let error = serde_json::from_str::<Interval>("\"P1D\"").unwrap_err();
+        assert!(error.to_string().starts_with("invalid interval \"P1D\""));
+        assert!(serde_json::from_str::<Interval>("12").is_err());
+        let json = r#"{"default": "1 day", "iso": "1 day", "sql": "0", "parts": {}}"#;
+        assert!(serde_json::from_str::<Formats>(json).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_lenient() {
+        let expected = Interval::new(14, 3, 4 * HOUR);
+        for input in [
+            "1 year 2 mons 3 days 04:00:00",
+            "P1Y2M3DT4H",
+            "+1-2 +3 +4:00:00",
+            "@ 1 year 2 mons 3 days 4 hours",
+        ] {
+            let json = serde_json::json!({ "interval": input });
+            let result: Lenient = serde_json::from_value(json).unwrap();
+            assert_eq!(result.interval, expected, "{}", input);
+        }
+        let json = serde_json::json!({ "interval": "soon" });
+        assert!(serde_json::from_value::<Lenient>(json).is_err());
+    }
+}
@@ -0,0 +1,114 @@
+use super::binary::BINARY_LEN;
+use crate::Interval;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::types::PgInterval;
+use sqlx::postgres::{
+    PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use sqlx::{Decode, Encode, Type};
+
+impl Type<Postgres> for Interval {
+    fn type_info() -> PgTypeInfo {
+        <PgInterval as Type<Postgres>>::type_info()
+    }
+}
+
+impl PgHasArrayType for Interval {
+    fn array_type_info() -> PgTypeInfo {
+        <PgInterval as PgHasArrayType>::array_type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for Interval {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend_from_slice(&self.to_binary());
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        BINARY_LEN
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Interval {
+    /// Unlike `PgInterval` an interval can also be decoded from the text format
+    /// used by unprepared queries, in any of postgres' interval styles.
+    fn decode(value: PgValueRef<'r>) -> Result<Interval, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => Ok(Interval::from_binary(value.as_bytes()?)?),
+            PgValueFormat::Text => Ok(value.as_str()?.parse::<Interval>()?),
+        }
+    }
+}
+
+impl From<PgInterval> for Interval {
+    fn from(interval: PgInterval) -> Interval {
+        Interval::new(interval.months, interval.days, interval.microseconds)
+    }
+}
+
+impl From<Interval> for PgInterval {
+    fn from(interval: Interval) -> PgInterval {
+        PgInterval {
+            months: interval.months,
+            days: interval.days,
+            microseconds: interval.microseconds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode<'q, T: Encode<'q, Postgres>>(value: T) -> Vec<u8> {
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(value.encode_by_ref(&mut buf), Ok(IsNull::No)));
+        buf.to_vec()
+    }
+
+    #[test]
+    fn test_type_info() {
+        assert_eq!(
+            <Interval as Type<Postgres>>::type_info(),
+            <PgInterval as Type<Postgres>>::type_info()
+        );
+        assert_eq!(
+            <Interval as PgHasArrayType>::array_type_info(),
+            <PgInterval as PgHasArrayType>::array_type_info()
+        );
+    }
+
+    #[test]
+    fn test_encodes_like_pg_interval() {
+        for interval in [
+            Interval::new(14, -3, 4_000_000),
+            Interval::INFINITY,
+            Interval::NEG_INFINITY,
+        ] {
+            let bytes = encode(interval);
+            assert_eq!(bytes, encode(PgInterval::from(interval)));
+            assert_eq!(Interval::from_binary(&bytes), Ok(interval));
+        }
+    }
+
+    #[test]
+    fn test_encodes_like_rust_postgres() {
+        let interval = Interval::new(-1, 2, -3);
+        assert_eq!(encode(interval), interval.to_binary());
+        assert_eq!(interval.size_hint(), BINARY_LEN);
+    }
+
+    #[test]
+    fn test_pg_interval_conversion() {
+        let pg_interval = PgInterval {
+            months: 14,
+            days: -3,
+            microseconds: 4_000_000,
+        };
+        let interval = Interval::from(pg_interval);
+        assert_eq!(interval, Interval::new(14, -3, 4_000_000));
+        assert_eq!(PgInterval::from(interval), pg_interval);
+    }
+}
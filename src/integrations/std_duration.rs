@@ -0,0 +1,185 @@
+use super::conversion_error::ConversionError;
+use crate::pg_interval::{MICROS_PER_SECOND, NANOS_PER_MICRO};
+use crate::{DurationPolicy, Interval, RoundingMode};
+use std::time::Duration;
+
+impl Interval {
+    /// Converts the `std::time::Duration` into an interval the same way postgres
+    /// represents the difference between two timestamps, with whole 24 hour periods
+    /// as days and the rest as time. Any units smaller than a microsecond are rounded
+    /// using the rounding mode. Days beyond the range of the interval's days are kept
+    /// in the time part, and an error is returned if they don't fit there either.
+    pub fn from_std_duration(
+        duration: Duration,
+        rounding: RoundingMode,
+    ) -> Result<Interval, ConversionError> {
+        let micros = duration.as_secs() as i128 * MICROS_PER_SECOND as i128
+            + rounding.div_thousand(duration.subsec_nanos() as i64) as i128;
+        Interval::from_duration_micros(micros)
+    }
+
+    /// Converts the interval into a `std::time::Duration`, using the policy to turn
+    /// any months and days into an absolute amount of time. Will return an error if
+    /// the policy rejects the interval, the interval is infinite or its total length
+    /// is negative.
+    pub fn to_std_duration(&self, policy: DurationPolicy) -> Result<Duration, ConversionError> {
+        let micros = self.total_micros(policy)?;
+        if micros < 0 {
+            return Err(ConversionError::from_negative(
+                "Negative intervals can not be converted to a std duration.",
+            ));
+        }
+        // the seconds of the largest interval fit easily in a u64.
+        let secs = (micros / MICROS_PER_SECOND as i128) as u64;
+        let nanos = (micros % MICROS_PER_SECOND as i128) as u32 * NANOS_PER_MICRO as u32;
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+impl TryFrom<Duration> for Interval {
+    type Error = ConversionError;
+    /// Truncates any units smaller than a microsecond, use
+    /// `Interval::from_std_duration` to round them instead.
+    fn try_from(duration: Duration) -> Result<Interval, ConversionError> {
+        Interval::from_std_duration(duration, RoundingMode::Truncate)
+    }
+}
+
+impl TryFrom<Interval> for Duration {
+    type Error = ConversionError;
+    /// Only intervals without months or days can be converted, use
+    /// `Interval::to_std_duration` to choose how they are converted.
+    fn try_from(interval: Interval) -> Result<Duration, ConversionError> {
+        interval.to_std_duration(DurationPolicy::Reject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pg_interval::MICROS_PER_DAY;
+
+    const HOUR: i64 = 3_600_000_000;
+
+    #[test]
+    fn test_from_std_duration() {
+        let duration = Duration::new(90_061, 500_999);
+        assert_eq!(
+            Interval::try_from(duration),
+            Ok(Interval::new(0, 1, 3_661_000_500))
+        );
+        assert_eq!(
+            Interval::try_from(Duration::from_secs(3_600)),
+            Ok(Interval::new(0, 0, HOUR))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_from_std_duration_matches_chrono() {
+        let interval = Interval::try_from(Duration::from_secs(36 * 3_600));
+        assert_eq!(interval, Interval::try_from(chrono::Duration::hours(36)));
+        assert_eq!(interval, Ok(Interval::new(0, 1, 12 * HOUR)));
+    }
+
+    #[test]
+    fn test_from_std_duration_rounds() {
+        let duration = Duration::from_nanos(2_500);
+        assert_eq!(
+            Interval::from_std_duration(duration, RoundingMode::Truncate),
+            Ok(Interval::new(0, 0, 2))
+        );
+        assert_eq!(
+            Interval::from_std_duration(duration, RoundingMode::HalfEven),
+            Ok(Interval::new(0, 0, 2))
+        );
+        assert_eq!(
+            Interval::from_std_duration(duration, RoundingMode::HalfUp),
+            Ok(Interval::new(0, 0, 3))
+        );
+        let duration = Duration::new(86_399, 999_999_500);
+        assert_eq!(
+            Interval::from_std_duration(duration, RoundingMode::HalfUp),
+            Ok(Interval::new(0, 1, 0))
+        );
+    }
+
+    #[test]
+    fn test_from_std_duration_overflow() {
+        let duration = Duration::from_secs((i32::MAX as u64 + 10) * 86_400 + 3 * 3_600);
+        assert_eq!(
+            Interval::try_from(duration),
+            Ok(Interval::new(0, i32::MAX, 10 * MICROS_PER_DAY + 3 * HOUR))
+        );
+        assert!(matches!(
+            Interval::try_from(Duration::MAX),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_std_duration_reject() {
+        let interval = Interval::new(0, 0, 2 * HOUR + 1);
+        assert_eq!(
+            Duration::try_from(interval),
+            Ok(Duration::new(7_200, 1_000))
+        );
+        assert!(matches!(
+            Duration::try_from(Interval::new(0, 1, 0)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+        assert!(matches!(
+            Duration::try_from(Interval::new(1, 0, 0)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_std_duration_thirty_day_months() {
+        // interval '1 year 1 mon 1 day 1 hour'
+        let interval = Interval::new(13, 1, HOUR);
+        let expected = (13 * 30 + 1) * 86_400 + 3_600;
+        assert_eq!(
+            interval.to_std_duration(DurationPolicy::ThirtyDayMonths),
+            Ok(Duration::from_secs(expected))
+        );
+    }
+
+    #[test]
+    fn test_to_std_duration_julian_years() {
+        // extract(epoch from interval '1 year 1 mon') = 34149600
+        let interval = Interval::new(13, 0, 0);
+        assert_eq!(
+            interval.to_std_duration(DurationPolicy::JulianYears),
+            Ok(Duration::from_secs(34_149_600))
+        );
+    }
+
+    #[test]
+    fn test_to_std_duration_negative() {
+        assert!(matches!(
+            Interval::new(0, 0, -1).to_std_duration(DurationPolicy::Reject),
+            Err(ConversionError::Negative(_))
+        ));
+        // '1 day -1 hour' is positive overall.
+        let interval = Interval::new(0, 1, -HOUR);
+        assert_eq!(
+            interval.to_std_duration(DurationPolicy::ThirtyDayMonths),
+            Ok(Duration::from_secs(23 * 3_600))
+        );
+        assert!(matches!(
+            Interval::new(-1, 29, 0).to_std_duration(DurationPolicy::ThirtyDayMonths),
+            Err(ConversionError::Negative(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_std_duration_extremes() {
+        let duration = Interval::MAX.to_std_duration(DurationPolicy::ThirtyDayMonths);
+        assert!(duration.is_ok());
+        assert!(matches!(
+            Interval::INFINITY.to_std_duration(DurationPolicy::JulianYears),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+}
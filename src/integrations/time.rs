@@ -0,0 +1,316 @@
+use super::conversion_error::ConversionError;
+use crate::interval_arithmetic::{IntervalArithmetic, impl_interval_ops};
+use crate::pg_interval::{MICROS_PER_SECOND, MONTHS_PER_YEAR, NANOS_PER_MICRO};
+use crate::pg_interval_age::days_in_month;
+use crate::{DurationPolicy, Interval, IntervalTimestamp, RoundingMode};
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+impl Interval {
+    /// Converts the `time::Duration` into an interval the same way postgres
+    /// represents the difference between two timestamps, with whole 24 hour periods
+    /// as days and the rest as time. Any units smaller than a microsecond are rounded
+    /// using the rounding mode. Days beyond the range of the interval's days are kept
+    /// in the time part, and an error is returned if they don't fit there either.
+    pub fn from_time_duration(
+        duration: Duration,
+        rounding: RoundingMode,
+    ) -> Result<Interval, ConversionError> {
+        let micros = duration.whole_seconds() as i128 * MICROS_PER_SECOND as i128
+            + rounding.div_thousand(duration.subsec_nanoseconds() as i64) as i128;
+        Interval::from_duration_micros(micros)
+    }
+
+    /// Converts the interval into a `time::Duration`, using the policy to turn any
+    /// months and days into an absolute amount of time. Will return an error if the
+    /// policy rejects the interval or the interval is infinite.
+    pub fn to_time_duration(&self, policy: DurationPolicy) -> Result<Duration, ConversionError> {
+        let micros = self.total_micros(policy)?;
+        // the seconds of the largest interval fit easily in an i64.
+        let secs = (micros / MICROS_PER_SECOND as i128) as i64;
+        let nanos = (micros % MICROS_PER_SECOND as i128) as i32 * NANOS_PER_MICRO as i32;
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+impl TryFrom<Duration> for Interval {
+    type Error = ConversionError;
+    /// Truncates any units smaller than a microsecond, use
+    /// `Interval::from_time_duration` to round them instead.
+    fn try_from(duration: Duration) -> Result<Interval, ConversionError> {
+        Interval::from_time_duration(duration, RoundingMode::Truncate)
+    }
+}
+
+impl TryFrom<Interval> for Duration {
+    type Error = ConversionError;
+    /// Only intervals without months or days can be converted, use
+    /// `Interval::to_time_duration` to choose how they are converted.
+    fn try_from(interval: Interval) -> Result<Duration, ConversionError> {
+        interval.to_time_duration(DurationPolicy::Reject)
+    }
+}
+
+/// Adds the months to the date, clamping to the end of the month when the day
+/// doesn't exist.
+fn add_months(date: Date, months: i32) -> Option<Date> {
+    let months = date.year() as i64 * MONTHS_PER_YEAR as i64
+        + (u8::from(date.month()) as i64 - 1)
+        + months as i64;
+    let year = i32::try_from(months.div_euclid(MONTHS_PER_YEAR as i64)).ok()?;
+    let month = months.rem_euclid(MONTHS_PER_YEAR as i64) + 1;
+    let day = (date.day() as i64).min(days_in_month(year as i64, month));
+    Date::from_calendar_date(year, Month::try_from(month as u8).ok()?, day as u8).ok()
+}
+
+impl IntervalArithmetic for PrimitiveDateTime {
+    type Output = PrimitiveDateTime;
+
+    fn checked_add_interval(self, interval: Interval) -> Option<PrimitiveDateTime> {
+        if interval.is_infinite() {
+            return None;
+        }
+        let date = add_months(self.date(), interval.months)?;
+        PrimitiveDateTime::new(date, self.time())
+            .checked_add(Duration::days(interval.days as i64))?
+            .checked_add(Duration::microseconds(interval.microseconds))
+    }
+}
+
+impl IntervalArithmetic for Date {
+    /// Like postgres adding an interval to a date produces a timestamp.
+    type Output = PrimitiveDateTime;
+
+    fn checked_add_interval(self, interval: Interval) -> Option<PrimitiveDateTime> {
+        PrimitiveDateTime::new(self, Time::MIDNIGHT).checked_add_interval(interval)
+    }
+
+    fn checked_sub_interval(self, interval: Interval) -> Option<PrimitiveDateTime> {
+        PrimitiveDateTime::new(self, Time::MIDNIGHT).checked_sub_interval(interval)
+    }
+}
+
+impl IntervalArithmetic for OffsetDateTime {
+    type Output = OffsetDateTime;
+
+    /// The interval is added to the local date and time, keeping the offset. Since
+    /// the offset is fixed adding `1 day` is the same as adding `24 hours`.
+    fn checked_add_interval(self, interval: Interval) -> Option<OffsetDateTime> {
+        PrimitiveDateTime::new(self.date(), self.time())
+            .checked_add_interval(interval)
+            .map(|local| local.assume_offset(self.offset()))
+    }
+}
+
+impl IntervalArithmetic for Time {
+    type Output = Time;
+
+    fn checked_add_interval(self, interval: Interval) -> Option<Time> {
+        if interval.is_infinite() {
+            return None;
+        }
+        Some(self + Duration::microseconds(interval.microseconds))
+    }
+
+    fn checked_sub_interval(self, interval: Interval) -> Option<Time> {
+        if interval.is_infinite() {
+            return None;
+        }
+        Some(self - Duration::microseconds(interval.microseconds))
+    }
+}
+
+impl IntervalTimestamp for PrimitiveDateTime {
+    fn unix_micros(self) -> Option<i64> {
+        let nanos = self.assume_utc().unix_timestamp_nanos();
+        i64::try_from(nanos.div_euclid(NANOS_PER_MICRO as i128)).ok()
+    }
+}
+
+impl IntervalTimestamp for Date {
+    fn unix_micros(self) -> Option<i64> {
+        PrimitiveDateTime::new(self, Time::MIDNIGHT).unix_micros()
+    }
+}
+
+impl_interval_ops!(PrimitiveDateTime, Date, OffsetDateTime);
+impl_interval_ops!(time: Time);
+
+impl From<UtcOffset> for Interval {
+    /// The offset east of UTC as a time interval, matching the intervals postgres
+    /// accepts for `timezone(interval, timestamp)`.
+    fn from(offset: UtcOffset) -> Interval {
+        Interval::new(0, 0, offset.whole_seconds() as i64 * MICROS_PER_SECOND)
+    }
+}
+
+impl TryFrom<Interval> for UtcOffset {
+    type Error = ConversionError;
+    /// Treats the interval as the offset east of UTC like postgres'
+    /// `timezone(interval, timestamp)`. Any fractional seconds are truncated.
+    fn try_from(interval: Interval) -> Result<UtcOffset, ConversionError> {
+        if interval.months != 0 || interval.days != 0 {
+            return Err(ConversionError::from_unrepresentable(
+                "Interval time zone must not include months or days.",
+            ));
+        }
+        i32::try_from(interval.microseconds / MICROS_PER_SECOND)
+            .ok()
+            .and_then(|secs| UtcOffset::from_whole_seconds(secs).ok())
+            .ok_or_else(|| {
+                ConversionError::from_out_of_range("Interval time zone exceeds the offset range.")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval_arithmetic::test_cases;
+    use crate::pg_interval::MICROS_PER_DAY;
+
+    const HOUR: i64 = 3_600_000_000;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::from_calendar_date(year, Month::try_from(month as u8).unwrap(), day as u8).unwrap()
+    }
+
+    fn timestamp(year: i32, month: u32, day: u32, hour: u32) -> PrimitiveDateTime {
+        date(year, month, day).with_hms(hour as u8, 0, 0).unwrap()
+    }
+
+    fn time(hour: u32, min: u32) -> Time {
+        Time::from_hms(hour as u8, min as u8, 0).unwrap()
+    }
+
+    #[test]
+    fn test_from_time_duration() {
+        let duration = Duration::days(2) + Duration::minutes(3);
+        assert_eq!(
+            Interval::try_from(duration),
+            Ok(Interval::new(0, 2, 180_000_000))
+        );
+        assert_eq!(
+            Interval::try_from(-duration),
+            Ok(Interval::new(0, -2, -180_000_000))
+        );
+        assert_eq!(
+            Interval::try_from(Duration::nanoseconds(1_999)),
+            Ok(Interval::new(0, 0, 1))
+        );
+    }
+
+    #[test]
+    fn test_from_time_duration_rounds() {
+        let duration = Duration::nanoseconds(-2_500);
+        assert_eq!(
+            Interval::from_time_duration(duration, RoundingMode::Truncate),
+            Ok(Interval::new(0, 0, -2))
+        );
+        assert_eq!(
+            Interval::from_time_duration(duration, RoundingMode::HalfEven),
+            Ok(Interval::new(0, 0, -2))
+        );
+        assert_eq!(
+            Interval::from_time_duration(duration, RoundingMode::HalfUp),
+            Ok(Interval::new(0, 0, -3))
+        );
+    }
+
+    #[test]
+    fn test_from_time_duration_overflow() {
+        let duration = Duration::days(i32::MAX as i64 + 10) + Duration::hours(3);
+        assert_eq!(
+            Interval::try_from(duration),
+            Ok(Interval::new(0, i32::MAX, 10 * MICROS_PER_DAY + 3 * HOUR))
+        );
+        assert!(matches!(
+            Interval::try_from(Duration::MAX),
+            Err(ConversionError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            Interval::try_from(Duration::MIN),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_time_duration() {
+        assert_eq!(
+            Duration::try_from(Interval::new(0, 0, -90_000_001)),
+            Ok(Duration::microseconds(-90_000_001))
+        );
+        assert!(matches!(
+            Duration::try_from(Interval::new(0, 1, 0)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+        // interval '1 mon -1 day'
+        assert_eq!(
+            Interval::new(1, -1, 0).to_time_duration(DurationPolicy::ThirtyDayMonths),
+            Ok(Duration::days(29))
+        );
+        // extract(epoch from interval '-1 year') = -31557600
+        assert_eq!(
+            Interval::new(-12, 0, 0).to_time_duration(DurationPolicy::JulianYears),
+            Ok(Duration::seconds(-31_557_600))
+        );
+        assert!(matches!(
+            Interval::INFINITY.to_time_duration(DurationPolicy::ThirtyDayMonths),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_timestamp_arithmetic() {
+        test_cases::check_timestamp_arithmetic(date, timestamp);
+    }
+
+    #[test]
+    fn test_time_arithmetic() {
+        test_cases::check_time_arithmetic(time);
+    }
+
+    #[test]
+    fn test_age() {
+        test_cases::check_age(timestamp);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let interval = Interval::new(0, 1, 0);
+        assert_eq!(interval.checked_add_to(PrimitiveDateTime::MAX), None);
+        assert_eq!(interval.checked_sub_from(PrimitiveDateTime::MIN), None);
+        assert_eq!(
+            Interval::INFINITY.checked_add_to(OffsetDateTime::UNIX_EPOCH),
+            None
+        );
+    }
+
+    #[test]
+    fn test_offset_date_time_keeps_offset() {
+        let offset = UtcOffset::from_hms(-5, 0, 0).unwrap();
+        let start = timestamp(2024, 1, 31, 22).assume_offset(offset);
+        let result = start + Interval::new(1, 0, 3 * HOUR);
+        assert_eq!(result, timestamp(2024, 3, 1, 1).assume_offset(offset));
+        assert_eq!(result.offset(), offset);
+        assert_eq!(
+            result - Interval::new(0, 0, 3 * HOUR),
+            timestamp(2024, 2, 29, 22).assume_offset(offset)
+        );
+    }
+
+    #[test]
+    fn test_utc_offset_conversion() {
+        let offset = UtcOffset::from_hms(5, 30, 0).unwrap();
+        let interval = Interval::from(offset);
+        assert_eq!(interval, Interval::new(0, 0, 5 * HOUR + 30 * 60_000_000));
+        assert_eq!(UtcOffset::try_from(interval), Ok(offset));
+        assert!(matches!(
+            UtcOffset::try_from(Interval::new(0, 1, 0)),
+            Err(ConversionError::Unrepresentable(_))
+        ));
+        assert!(matches!(
+            UtcOffset::try_from(Interval::new(0, 0, 30 * HOUR)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+}
@@ -0,0 +1,333 @@
+use crate::Interval;
+
+/// Date and time types an `Interval` can be added to or subtracted from using
+/// postgres' semantics. The months are applied first, clamping to the end of the
+/// month when the day doesn't exist, followed by the days and finally the time.
+///
+/// Postgres turns a timestamp into an infinite timestamp when an infinite interval
+/// is added to it, which the date and time types can't represent, so the checked
+/// arithmetic returns `None` for infinite intervals instead. Times only use the
+/// time part of the interval and wrap around midnight, so for them infinite
+/// intervals are the only failure.
+pub trait IntervalArithmetic: Sized {
+    /// The type produced by the arithmetic, e.g. postgres turns a `date` into a
+    /// `timestamp` when an interval is added to it.
+    type Output;
+
+    /// Adds the interval and returns `None` if the result is out of range.
+    fn checked_add_interval(self, interval: Interval) -> Option<Self::Output>;
+
+    /// Subtracts the interval and returns `None` if the result is out of range.
+    fn checked_sub_interval(self, interval: Interval) -> Option<Self::Output> {
+        self.checked_add_interval(interval.checked_neg()?)
+    }
+}
+
+impl Interval {
+    /// Checked addition of the interval to a date or time. Computes `value + Interval`
+    /// and `None` if the result is out of range.
+    pub fn checked_add_to<T: IntervalArithmetic>(self, value: T) -> Option<T::Output> {
+        value.checked_add_interval(self)
+    }
+
+    /// Checked subtraction of the interval from a date or time. Computes
+    /// `value - Interval` and `None` if the result is out of range.
+    pub fn checked_sub_from<T: IntervalArithmetic>(self, value: T) -> Option<T::Output> {
+        value.checked_sub_interval(self)
+    }
+}
+
+/// Implements `+` and `-` with an `Interval` for types implementing
+/// `IntervalArithmetic`, panicking when the checked arithmetic returns `None`.
+/// Times only fail for infinite intervals, so they get their own messages.
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+macro_rules! impl_interval_ops {
+    (@impl [$($generics:tt)*] $ty:ty, $add_msg:literal, $sub_msg:literal) => {
+        impl<$($generics)*> std::ops::Add<Interval> for $ty {
+            type Output = <$ty as IntervalArithmetic>::Output;
+            fn add(self, interval: Interval) -> Self::Output {
+                self.checked_add_interval(interval).expect($add_msg)
+            }
+        }
+
+        impl<$($generics)*> std::ops::Sub<Interval> for $ty {
+            type Output = <$ty as IntervalArithmetic>::Output;
+            fn sub(self, interval: Interval) -> Self::Output {
+                self.checked_sub_interval(interval).expect($sub_msg)
+            }
+        }
+    };
+    (time: $($ty:ty),+ $(,)?) => {
+        $(
+            impl_interval_ops!(
+                @impl [] $ty,
+                "cannot add infinite interval to time",
+                "cannot subtract infinite interval from time"
+            );
+        )+
+    };
+    (<$($param:ident: $bound:path),+> $ty:ty) => {
+        impl_interval_ops!(
+            @impl [$($param: $bound),+] $ty,
+            "timestamp out of range",
+            "timestamp out of range"
+        );
+    };
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl_interval_ops!(
+                @impl [] $ty,
+                "timestamp out of range",
+                "timestamp out of range"
+            );
+        )+
+    };
+}
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+pub(crate) use impl_interval_ops;
+
+/// Postgres results shared by the tests of the date and time crates, each backend
+/// runs its own types through the same cases.
+#[cfg(all(test, any(feature = "chrono", feature = "time", feature = "jiff")))]
+pub(crate) mod test_cases {
+    use super::IntervalArithmetic;
+    use crate::{Interval, IntervalTimestamp};
+    use std::fmt::Debug;
+    use std::ops;
+
+    const HOUR: i64 = 3_600_000_000;
+    const MINUTE: i64 = 60_000_000;
+
+    /// A timestamp as its year, month, day and hour.
+    type Timestamp = (i32, u32, u32, u32);
+
+    /// A time as its hour and minute.
+    type Time = (u32, u32);
+
+    /// Timestamps at midnight are also checked as dates.
+    const ADD_CASES: [(&str, Timestamp, Interval, Timestamp); 5] = [
+        (
+            "date '2024-01-31' + interval '1 mon' = '2024-02-29 00:00:00'",
+            (2024, 1, 31, 0),
+            Interval::new(1, 0, 0),
+            (2024, 2, 29, 0),
+        ),
+        (
+            "date '2023-01-31' + interval '1 mon' = '2023-02-28 00:00:00'",
+            (2023, 1, 31, 0),
+            Interval::new(1, 0, 0),
+            (2023, 2, 28, 0),
+        ),
+        (
+            "timestamp '2024-01-31 10:00' + interval '1 mon 1 day' = '2024-03-01 10:00:00'",
+            (2024, 1, 31, 10),
+            Interval::new(1, 1, 0),
+            (2024, 3, 1, 10),
+        ),
+        (
+            "timestamp '2023-11-30 10:00' + interval '1 year 3 mons 2 hours' = '2025-02-28 12:00:00'",
+            (2023, 11, 30, 10),
+            Interval::new(15, 0, 2 * HOUR),
+            (2025, 2, 28, 12),
+        ),
+        (
+            "timestamp '2024-03-31 10:00' + interval '-1 mon -1 day -11 hours' = '2024-02-27 23:00:00'",
+            (2024, 3, 31, 10),
+            Interval::new(-1, -1, -11 * HOUR),
+            (2024, 2, 27, 23),
+        ),
+    ];
+
+    const SUB_CASES: [(&str, Timestamp, Interval, Timestamp); 3] = [
+        (
+            "timestamp '2024-03-31 10:00' - interval '1 mon' = '2024-02-29 10:00:00'",
+            (2024, 3, 31, 10),
+            Interval::new(1, 0, 0),
+            (2024, 2, 29, 10),
+        ),
+        (
+            "timestamp '2024-02-29 10:00' - interval '13 mons' = '2023-01-29 10:00:00'",
+            (2024, 2, 29, 10),
+            Interval::new(13, 0, 0),
+            (2023, 1, 29, 10),
+        ),
+        (
+            "date '2024-03-01' - interval '1 hour' = '2024-02-29 23:00:00'",
+            (2024, 3, 1, 0),
+            Interval::new(0, 0, HOUR),
+            (2024, 2, 29, 23),
+        ),
+    ];
+
+    /// The months and days of the interval are ignored.
+    const TIME_ADD_CASES: [(&str, Time, Interval, Time); 3] = [
+        (
+            "time '23:00' + interval '2 hours' = '01:00:00'",
+            (23, 0),
+            Interval::new(0, 0, 2 * HOUR),
+            (1, 0),
+        ),
+        (
+            "time '01:00' + interval '-50 hours' = '23:00:00'",
+            (1, 0),
+            Interval::new(0, 0, -50 * HOUR),
+            (23, 0),
+        ),
+        (
+            "time '10:00' + interval '-178956970 years -8 mons -2147483648 days +00:30' = '10:30:00'",
+            (10, 0),
+            Interval::new(i32::MIN, i32::MIN, 30 * MINUTE),
+            (10, 30),
+        ),
+    ];
+
+    const TIME_SUB_CASES: [(&str, Time, Interval, Time); 2] = [
+        (
+            "time '01:00' - interval '2 hours' = '23:00:00'",
+            (1, 0),
+            Interval::new(0, 0, 2 * HOUR),
+            (23, 0),
+        ),
+        (
+            "time '10:00' - interval '-178956970 years -8 mons -2147483648 days +00:30' = '09:30:00'",
+            (10, 0),
+            Interval::new(i32::MIN, i32::MIN, 30 * MINUTE),
+            (9, 30),
+        ),
+    ];
+
+    const AGE_CASES: [(&str, Timestamp, Timestamp, Interval); 4] = [
+        (
+            "age(timestamp '2001-04-10', timestamp '1957-06-13') = '43 years 9 mons 27 days'",
+            (2001, 4, 10, 0),
+            (1957, 6, 13, 0),
+            Interval::new(43 * 12 + 9, 27, 0),
+        ),
+        (
+            "age(timestamp '1957-06-13', timestamp '2001-04-10') = '-43 years -9 mons -27 days'",
+            (1957, 6, 13, 0),
+            (2001, 4, 10, 0),
+            Interval::new(-(43 * 12 + 9), -27, 0),
+        ),
+        (
+            "age(timestamp '2024-03-01 00:00', timestamp '2024-02-28 12:00') = '1 day 12:00:00'",
+            (2024, 3, 1, 0),
+            (2024, 2, 28, 12),
+            Interval::new(0, 1, 12 * HOUR),
+        ),
+        (
+            "age(timestamp '2024-03-31', timestamp '2024-02-29') = '1 mon 2 days'",
+            (2024, 3, 31, 0),
+            (2024, 2, 29, 0),
+            Interval::new(1, 2, 0),
+        ),
+    ];
+
+    const BETWEEN_CASES: [(&str, Timestamp, Timestamp, Interval); 2] = [
+        (
+            "timestamp '2024-03-01' - timestamp '2024-01-28 12:00' = '32 days 12:00:00'",
+            (2024, 3, 1, 0),
+            (2024, 1, 28, 12),
+            Interval::new(0, 32, 12 * HOUR),
+        ),
+        (
+            "timestamp '2024-01-28 12:00' - timestamp '2024-03-01' = '-32 days -12:00:00'",
+            (2024, 1, 28, 12),
+            (2024, 3, 1, 0),
+            Interval::new(0, -32, -12 * HOUR),
+        ),
+    ];
+
+    /// Runs the timestamp and date cases through both the operators and the
+    /// checked arithmetic.
+    pub(crate) fn check_timestamp_arithmetic<D, T>(
+        date: impl Fn(i32, u32, u32) -> D,
+        timestamp: impl Fn(i32, u32, u32, u32) -> T,
+    ) where
+        D: IntervalArithmetic<Output = T>
+            + ops::Add<Interval, Output = T>
+            + ops::Sub<Interval, Output = T>
+            + Copy,
+        T: IntervalArithmetic<Output = T>
+            + ops::Add<Interval, Output = T>
+            + ops::Sub<Interval, Output = T>
+            + Copy
+            + PartialEq
+            + Debug,
+    {
+        for (sql, (y, m, d, h), interval, (ey, em, ed, eh)) in ADD_CASES {
+            let expected = timestamp(ey, em, ed, eh);
+            assert_eq!(timestamp(y, m, d, h) + interval, expected, "{}", sql);
+            assert_eq!(
+                interval.checked_add_to(timestamp(y, m, d, h)),
+                Some(expected),
+                "{}",
+                sql
+            );
+            if h == 0 {
+                assert_eq!(date(y, m, d) + interval, expected, "{}", sql);
+            }
+        }
+        for (sql, (y, m, d, h), interval, (ey, em, ed, eh)) in SUB_CASES {
+            let expected = timestamp(ey, em, ed, eh);
+            assert_eq!(timestamp(y, m, d, h) - interval, expected, "{}", sql);
+            assert_eq!(
+                interval.checked_sub_from(timestamp(y, m, d, h)),
+                Some(expected),
+                "{}",
+                sql
+            );
+            if h == 0 {
+                assert_eq!(date(y, m, d) - interval, expected, "{}", sql);
+            }
+        }
+        for interval in [
+            Interval::new(i32::MAX, 0, 0),
+            Interval::new(0, 0, i64::MAX - 1),
+            Interval::INFINITY,
+        ] {
+            assert_eq!(interval.checked_add_to(date(2024, 1, 1)), None);
+            assert_eq!(interval.checked_add_to(timestamp(2024, 1, 1, 0)), None);
+        }
+        // negating the minimum months overflows.
+        for interval in [Interval::new(i32::MIN, 0, 0), Interval::NEG_INFINITY] {
+            assert_eq!(interval.checked_sub_from(date(2024, 1, 1)), None);
+            assert_eq!(interval.checked_sub_from(timestamp(2024, 1, 1, 0)), None);
+        }
+    }
+
+    /// Runs the time cases through both the operators and the checked arithmetic.
+    pub(crate) fn check_time_arithmetic<T>(time: impl Fn(u32, u32) -> T)
+    where
+        T: IntervalArithmetic<Output = T>
+            + ops::Add<Interval, Output = T>
+            + ops::Sub<Interval, Output = T>
+            + Copy
+            + PartialEq
+            + Debug,
+    {
+        for (sql, (h, m), interval, (eh, em)) in TIME_ADD_CASES {
+            assert_eq!(time(h, m) + interval, time(eh, em), "{}", sql);
+            assert_eq!(interval.checked_add_to(time(h, m)), Some(time(eh, em)));
+        }
+        for (sql, (h, m), interval, (eh, em)) in TIME_SUB_CASES {
+            assert_eq!(time(h, m) - interval, time(eh, em), "{}", sql);
+            assert_eq!(interval.checked_sub_from(time(h, m)), Some(time(eh, em)));
+        }
+        assert_eq!(Interval::INFINITY.checked_add_to(time(10, 0)), None);
+        assert_eq!(Interval::NEG_INFINITY.checked_sub_from(time(10, 0)), None);
+    }
+
+    /// Runs the `age` and timestamp subtraction cases.
+    pub(crate) fn check_age<T: IntervalTimestamp>(timestamp: impl Fn(i32, u32, u32, u32) -> T) {
+        for (sql, (y, m, d, h), (ey, em, ed, eh), expected) in AGE_CASES {
+            let interval = Interval::age(timestamp(y, m, d, h), timestamp(ey, em, ed, eh));
+            assert_eq!(interval, Ok(expected), "{}", sql);
+        }
+        for (sql, (y, m, d, h), (ey, em, ed, eh), expected) in BETWEEN_CASES {
+            let interval = Interval::between(timestamp(y, m, d, h), timestamp(ey, em, ed, eh));
+            assert_eq!(interval, Ok(expected), "{}", sql);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::integrations::conversion_error::ConversionError;
+use crate::pg_interval::MICROS_PER_DAY;
+use crate::{Interval, IntervalField};
+
+/// How the months and days of an interval become an absolute amount of time when
+/// converting it into a duration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DurationPolicy {
+    /// Only intervals without months or days can be converted, since their length
+    /// depends on the date they are applied to.
+    #[default]
+    Reject,
+    /// Months are 30 days and days are 24 hours, the same way postgres compares
+    /// intervals and `justify_interval` works.
+    ThirtyDayMonths,
+    /// Years are 365.25 days, the remaining months 30 days and days 24 hours, the
+    /// same way postgres computes `extract(epoch from interval)`.
+    JulianYears,
+}
+
+/// How units smaller than a microsecond are rounded when converting a duration
+/// into an interval, or units smaller than a millisecond when converting an
+/// interval into a format that only stores milliseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Drops the smaller units.
+    #[default]
+    Truncate,
+    /// Rounds to the nearest unit with halves going to the even one, the way
+    /// postgres rounds floating point seconds.
+    HalfEven,
+    /// Rounds to the nearest unit with halves going away from zero.
+    HalfUp,
+}
+
+impl RoundingMode {
+    /// Divides the value by a thousand, rounding the remainder using the rounding
+    /// mode, e.g. nanoseconds into microseconds or microseconds into milliseconds.
+    pub(crate) fn div_thousand(self, value: i64) -> i64 {
+        const DIVISOR: i64 = 1_000;
+        let quotient = value / DIVISOR;
+        let remainder = (value % DIVISOR).abs();
+        let round_away = match self {
+            RoundingMode::Truncate => false,
+            RoundingMode::HalfEven => {
+                remainder > DIVISOR / 2 || (remainder == DIVISOR / 2 && quotient % 2 != 0)
+            }
+            RoundingMode::HalfUp => remainder >= DIVISOR / 2,
+        };
+        if round_away {
+            quotient + value.signum()
+        } else {
+            quotient
+        }
+    }
+}
+
+impl Interval {
+    /// Splits the microseconds into an interval the same way postgres represents the
+    /// difference between two timestamps, with whole 24 hour periods as days and the
+    /// rest as time. Days beyond the range of the interval's days are kept in the time
+    /// part, and an error is returned if they don't fit there either.
+    pub(crate) fn from_duration_micros(micros: i128) -> Result<Interval, ConversionError> {
+        let days = (micros / MICROS_PER_DAY as i128).clamp(i32::MIN as i128, i32::MAX as i128);
+        i64::try_from(micros - days * MICROS_PER_DAY as i128)
+            .ok()
+            .map(|time| Interval::new(0, days as i32, time))
+            .filter(Interval::is_finite)
+            .ok_or_else(|| {
+                ConversionError::from_out_of_range("Duration exceeds the interval range.")
+            })
+    }
+
+    /// The total length of the interval in microseconds using the policy.
+    pub(crate) fn total_micros(&self, policy: DurationPolicy) -> Result<i128, ConversionError> {
+        if self.is_infinite() {
+            return Err(ConversionError::from_out_of_range(
+                "Infinite intervals can not be converted to a duration.",
+            ));
+        }
+        match policy {
+            DurationPolicy::Reject if self.months != 0 || self.days != 0 => {
+                Err(ConversionError::from_unrepresentable(
+                    "Intervals with months or days can not be converted to a duration.",
+                ))
+            }
+            DurationPolicy::Reject => Ok(self.microseconds as i128),
+            DurationPolicy::ThirtyDayMonths => Ok(self.cmp_value()),
+            // the epoch of a finite interval is always a number of microseconds.
+            DurationPolicy::JulianYears => Ok(self
+                .extract(IntervalField::Epoch)
+                .map_or(0, |epoch| epoch.value())),
+        }
+    }
+}
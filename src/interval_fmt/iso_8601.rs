@@ -1,50 +1,76 @@
 use crate::interval_norm::IntervalNorm;
+use std::fmt;
 
 impl IntervalNorm {
-    /// Produces a iso 8601 compliant interval string.
-    pub fn into_iso_8601(self) -> String {
+    /// Writes a iso 8601 compliant interval string.
+    pub fn write_iso_8601<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
         if self.is_zeroed() {
-            return "PT0S".to_owned();
+            return f.write_str("PT0S");
+        }
+        f.write_str("P")?;
+        if self.years != 0 {
+            write!(f, "{}Y", self.years)?;
+        }
+        if self.months != 0 {
+            write!(f, "{}M", self.months)?;
+        }
+        if self.days != 0 {
+            write!(f, "{}D", self.days)?;
         }
-        let mut year_interval = "P".to_owned();
-        let mut day_interval = "".to_owned();
-        let mut time_interval;
         if self.is_time_present() {
-            time_interval = "T".to_owned();
+            f.write_str("T")?;
             if self.hours != 0 {
-                time_interval.push_str(&format!("{}H", self.hours));
+                write!(f, "{}H", self.hours)?;
             }
             if self.minutes != 0 {
-                time_interval.push_str(&format!("{}M", self.minutes));
+                write!(f, "{}M", self.minutes)?;
             }
-            if self.seconds != 0 || self.microseconds != 0 {
-                let secs_with_micros = if self.seconds != 0 && self.microseconds != 0 {
-                    format!(
-                        "{}.{:06}",
-                        self.seconds,
-                        super::safe_abs_u64(self.microseconds)
-                    )
-                } else if self.microseconds != 0 {
-                    format!(".{:06}", super::safe_abs_u64(self.microseconds))
-                } else {
-                    format!("{}", self.seconds)
-                };
-                time_interval.push_str(&format!("{}S", secs_with_micros));
+            if self.seconds != 0 && self.microseconds != 0 {
+                write!(
+                    f,
+                    "{}.{:06}S",
+                    self.seconds,
+                    super::safe_abs_u64(self.microseconds)
+                )?;
+            } else if self.microseconds != 0 {
+                let sign = if self.microseconds < 0 { "-" } else { "" };
+                write!(
+                    f,
+                    "{}0.{:06}S",
+                    sign,
+                    super::safe_abs_u64(self.microseconds)
+                )?;
+            } else if self.seconds != 0 {
+                write!(f, "{}S", self.seconds)?;
             }
-        } else {
-            time_interval = "".to_owned();
         }
-        if self.years != 0 {
-            year_interval.push_str(&format!("{}Y", self.years));
-        }
-        if self.months != 0 {
-            year_interval.push_str(&format!("{}M", self.months));
-        }
-        if self.days != 0 {
-            day_interval.push_str(&format!("{}D", self.days));
+        Ok(())
+    }
+
+    /// Writes the interval in ISO 8601's alternative format, e.g.
+    /// `P0001-02-03T04:05:06`. Every field has its own sign, so negative fields are
+    /// written with a `-` in front of them.
+    pub fn write_iso_8601_alternative<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        let sign = |negative: bool| if negative { "-" } else { "" };
+        write!(
+            f,
+            "P{}{:04}-{}{:02}-{}{:02}T{}{:02}:{}{:02}:{}{:02}",
+            sign(self.years < 0),
+            super::safe_abs_u32(self.years),
+            sign(self.months < 0),
+            super::safe_abs_u32(self.months),
+            sign(self.days < 0),
+            super::safe_abs_u32(self.days),
+            sign(self.hours < 0),
+            super::safe_abs_u64(self.hours),
+            sign(self.minutes < 0),
+            super::safe_abs_u64(self.minutes),
+            sign(self.seconds < 0 || self.microseconds < 0),
+            super::safe_abs_u64(self.seconds),
+        )?;
+        if self.microseconds != 0 {
+            write!(f, ".{:06}", super::safe_abs_u64(self.microseconds))?;
         }
-        year_interval.push_str(&day_interval);
-        year_interval.push_str(&time_interval);
-        year_interval
+        Ok(())
     }
 }
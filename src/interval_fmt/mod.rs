@@ -2,7 +2,120 @@ mod iso_8601;
 mod postgres;
 mod sql;
 
+use crate::Interval;
+use crate::interval_norm::IntervalNorm;
+use crate::interval_parse::parse_error::ParseError;
+use std::fmt;
 use std::ops::Neg;
+use std::str::FromStr;
+
+/// The output formats of an interval, mirroring the values of postgres'
+/// `IntervalStyle` setting.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IntervalStyle {
+    /// `1 year 2 mons -3 days 04:05:06`, the server default.
+    #[default]
+    Postgres,
+    /// `@ 1 year 2 mons -3 days 4 hours 5 mins 6 secs`.
+    PostgresVerbose,
+    /// `+1-2 -3 +4:05:06`.
+    SqlStandard,
+    /// `P1Y2M-3DT4H5M6S`.
+    Iso8601,
+}
+
+impl IntervalStyle {
+    /// The name postgres uses for the style.
+    pub fn name(self) -> &'static str {
+        match self {
+            IntervalStyle::Postgres => "postgres",
+            IntervalStyle::PostgresVerbose => "postgres_verbose",
+            IntervalStyle::SqlStandard => "sql_standard",
+            IntervalStyle::Iso8601 => "iso_8601",
+        }
+    }
+}
+
+impl fmt::Display for IntervalStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for IntervalStyle {
+    type Err = ParseError;
+
+    /// Parses the style from its postgres name, ignoring case like postgres does.
+    fn from_str(value: &str) -> Result<IntervalStyle, ParseError> {
+        [
+            IntervalStyle::Postgres,
+            IntervalStyle::PostgresVerbose,
+            IntervalStyle::SqlStandard,
+            IntervalStyle::Iso8601,
+        ]
+        .into_iter()
+        .find(|style| style.name().eq_ignore_ascii_case(value.trim()))
+        .ok_or_else(|| ParseError::from_invalid_interval("Unknown interval style."))
+    }
+}
+
+/// Displays an interval in an interval style, returned by `Interval::display`.
+#[derive(Clone, Copy, Debug)]
+pub struct IntervalDisplay {
+    interval: Interval,
+    style: IntervalStyle,
+}
+
+impl fmt::Display for IntervalDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.interval.write_to(f, self.style)
+    }
+}
+
+impl Interval {
+    /// Returns a value that displays the interval in the style.
+    pub fn display(&self, style: IntervalStyle) -> IntervalDisplay {
+        IntervalDisplay {
+            interval: *self,
+            style,
+        }
+    }
+
+    /// Writes the interval in the style, without allocating.
+    pub fn write_to<W: fmt::Write>(&self, out: &mut W, style: IntervalStyle) -> fmt::Result {
+        match style {
+            IntervalStyle::Postgres => self.write_norm(out, IntervalNorm::write_postgres),
+            IntervalStyle::PostgresVerbose => {
+                self.write_norm(out, IntervalNorm::write_postgres_verbose)
+            }
+            IntervalStyle::SqlStandard => self.write_norm(out, IntervalNorm::write_sql),
+            IntervalStyle::Iso8601 => self.write_norm(out, IntervalNorm::write_iso_8601),
+        }
+    }
+
+    /// Writes the interval using the formatter, unless it is infinite which postgres
+    /// outputs the same way for every interval style.
+    pub(crate) fn write_norm<W: fmt::Write>(
+        &self,
+        out: &mut W,
+        write: fn(&IntervalNorm, &mut W) -> fmt::Result,
+    ) -> fmt::Result {
+        if *self == Interval::INFINITY {
+            out.write_str("infinity")
+        } else if *self == Interval::NEG_INFINITY {
+            out.write_str("-infinity")
+        } else {
+            write(&IntervalNorm::from(self), out)
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    /// Displays the interval in the `postgres` style, the server default.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f, IntervalStyle::Postgres)
+    }
+}
 
 /// Safely maps a i64 value to a unsigned number
 /// without any overflow issues.
@@ -34,20 +147,109 @@ fn safe_abs_u32(mut num: i32) -> u32 {
     }
 }
 
-/// Pads a i64 value with a width of 2.
-fn pad_i64(val: i64) -> String {
-    let num = if val < 0 {
-        safe_abs_u64(val)
-    } else {
-        val as u64
-    };
-    format!("{:02}", num)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const STYLES: [IntervalStyle; 4] = [
+        IntervalStyle::Postgres,
+        IntervalStyle::PostgresVerbose,
+        IntervalStyle::SqlStandard,
+        IntervalStyle::Iso8601,
+    ];
+
+    #[test]
+    fn test_display() {
+        let interval = Interval::new(14, -3, 4 * 3_600_000_000 + 5_000_000);
+        assert_eq!(interval.to_string(), "1 year 2 mons -3 days 04:00:05");
+        assert_eq!(
+            Interval::new(-14, -3, -5_000_000)
+                .display(IntervalStyle::PostgresVerbose)
+                .to_string(),
+            "@ 1 year 2 mons 3 days 5 secs ago"
+        );
+        assert_eq!(
+            interval.display(IntervalStyle::SqlStandard).to_string(),
+            "+1-2 -3 +4:00:05"
+        );
+        assert_eq!(
+            interval.display(IntervalStyle::Iso8601).to_string(),
+            "P1Y2M-3DT4H5S"
+        );
+    }
+
+    #[test]
+    fn test_display_matches_to_methods() {
+        let interval = Interval::new(-1, 2, -3_000_004);
+        assert_eq!(
+            interval.display(IntervalStyle::Postgres).to_string(),
+            interval.to_postgres()
+        );
+        assert_eq!(
+            interval.display(IntervalStyle::PostgresVerbose).to_string(),
+            interval.to_postgres_verbose()
+        );
+        assert_eq!(
+            interval.display(IntervalStyle::SqlStandard).to_string(),
+            interval.to_sql()
+        );
+        assert_eq!(
+            interval.display(IntervalStyle::Iso8601).to_string(),
+            interval.to_iso_8601()
+        );
+    }
+
+    #[test]
+    fn test_write_to() {
+        let mut out = String::from("timeout=");
+        Interval::new(0, 0, 1_500_000)
+            .write_to(&mut out, IntervalStyle::Iso8601)
+            .unwrap();
+        out.push(',');
+        Interval::NEG_INFINITY
+            .write_to(&mut out, IntervalStyle::SqlStandard)
+            .unwrap();
+        assert_eq!(out, "timeout=PT1.500000S,-infinity");
+    }
+
+    #[test]
+    fn test_write_to_infinity() {
+        for style in STYLES {
+            assert_eq!(Interval::INFINITY.display(style).to_string(), "infinity");
+            assert_eq!(
+                Interval::NEG_INFINITY.display(style).to_string(),
+                "-infinity"
+            );
+        }
+    }
+
+    #[test]
+    fn test_style_names() {
+        for style in STYLES {
+            assert_eq!(style.to_string().parse(), Ok(style));
+            assert_eq!(style.name().to_uppercase().parse(), Ok(style));
+        }
+        assert_eq!("sql_standard".parse(), Ok(IntervalStyle::SqlStandard));
+        assert_eq!(IntervalStyle::default(), IntervalStyle::Postgres);
+        assert!("german".parse::<IntervalStyle>().is_err());
+    }
+
+    #[test]
+    fn test_parse_with_style() {
+        let interval = Interval::new(-14, -3, -4_000_005);
+        for style in STYLES {
+            let output = interval.display(style).to_string();
+            assert_eq!(
+                Interval::parse_with_style(&output, style),
+                Ok(interval),
+                "{}",
+                output
+            );
+        }
+        assert!(Interval::parse_with_style("P1D", IntervalStyle::Postgres).is_err());
+        assert!(Interval::parse_with_style("1 day", IntervalStyle::Iso8601).is_err());
+    }
+
     #[test]
     fn abs_safe_u32() {
         let min = i32::MIN;
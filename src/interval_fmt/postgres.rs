@@ -1,14 +1,15 @@
 use crate::interval_norm::IntervalNorm;
+use std::fmt;
 
-fn get_year_suffix(value: i32) -> &'static str {
+fn get_year_suffix(value: i64) -> &'static str {
     if value == 1 { "year" } else { "years" }
 }
 
-fn get_mon_suffix(value: i32) -> &'static str {
+fn get_mon_suffix(value: i64) -> &'static str {
     if value == 1 { "mon" } else { "mons" }
 }
 
-fn get_day_suffix(value: i32) -> &'static str {
+fn get_day_suffix(value: i64) -> &'static str {
     if value == 1 { "day" } else { "days" }
 }
 
@@ -28,149 +29,91 @@ fn get_sec_suffix(seconds: i64, microseconds: i64) -> &'static str {
     }
 }
 
+/// Writes the absolute value with its unit when it is set, the sign is written as
+/// `ago` at the end instead.
+fn write_verbose_part<W: fmt::Write>(
+    f: &mut W,
+    value: i64,
+    suffix: fn(i64) -> &'static str,
+) -> fmt::Result {
+    if value == 0 {
+        return Ok(());
+    }
+    let abs_value = value.abs();
+    write!(f, " {} {}", abs_value, suffix(abs_value))
+}
+
 impl IntervalNorm {
-    /// Produces a postgres compliant interval string.
-    pub fn into_postgres(self) -> String {
+    /// Writes a postgres compliant interval string.
+    pub fn write_postgres<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
         if self.is_zeroed() {
-            return "00:00:00".to_owned();
-        }
-        let mut year_interval = "".to_owned();
-        let mut day_interval = "".to_owned();
-        let time_interval = self.get_postgres_time_interval();
-        if self.is_day_present() {
-            day_interval = format!("{} {} ", self.days, get_day_suffix(self.days))
+            return f.write_str("00:00:00");
         }
-        if self.is_year_month_present() {
-            if self.years != 0 {
-                year_interval.push_str(&format!("{} {} ", self.years, get_year_suffix(self.years)))
-            }
-            if self.months != 0 {
-                year_interval.push_str(&format!(
-                    "{} {} ",
-                    self.months,
-                    get_mon_suffix(self.months)
-                ));
+        let mut separator = "";
+        let parts = [
+            (self.years as i64, get_year_suffix(self.years as i64)),
+            (self.months as i64, get_mon_suffix(self.months as i64)),
+            (self.days as i64, get_day_suffix(self.days as i64)),
+        ];
+        for (value, suffix) in parts {
+            if value != 0 {
+                write!(f, "{}{} {}", separator, value, suffix)?;
+                separator = " ";
             }
         }
-        year_interval.push_str(&day_interval);
-        year_interval.push_str(&time_interval);
-        year_interval.trim().to_owned()
-    }
-
-    fn get_postgres_time_interval(&self) -> String {
-        let mut time_interval = "".to_owned();
         if self.is_time_present() {
-            let sign = if !self.is_time_interval_pos() {
-                "-".to_owned()
-            } else {
-                "".to_owned()
-            };
-            let hours = super::pad_i64(self.hours);
-            time_interval.push_str(
-                &(sign
-                    + &hours
-                    + ":"
-                    + &super::pad_i64(self.minutes)
-                    + ":"
-                    + &super::pad_i64(self.seconds)),
-            );
-            if self.microseconds != 0 {
-                time_interval.push_str(&format!(".{:06}", super::safe_abs_u64(self.microseconds)))
-            }
+            f.write_str(separator)?;
+            self.write_postgres_time(f)?;
         }
-        time_interval
+        Ok(())
     }
 
-    /// Produces a postgres_verbose compliant interval string.
-    pub fn into_postgres_verbose(self) -> String {
-        let is_negative = !self.is_time_interval_pos()
-            && (self.years < 0
-                || self.months < 0
-                || self.days < 0
-                || self.hours < 0
-                || self.minutes < 0
-                || self.seconds < 0
-                || self.microseconds < 0);
-
-        let mut parts = Vec::new();
-
-        if self.years != 0 {
-            let abs_years = if self.years < 0 {
-                -self.years
-            } else {
-                self.years
-            };
-            parts.push(format!("{} {}", abs_years, get_year_suffix(abs_years)));
-        }
-
-        if self.months != 0 {
-            let abs_months = if self.months < 0 {
-                -self.months
-            } else {
-                self.months
-            };
-            parts.push(format!("{} {}", abs_months, get_mon_suffix(abs_months)));
-        }
-
-        if self.days != 0 {
-            let abs_days = if self.days < 0 { -self.days } else { self.days };
-            parts.push(format!("{} {}", abs_days, get_day_suffix(abs_days)));
-        }
-
-        if self.hours != 0 {
-            let abs_hours = if self.hours < 0 {
-                -self.hours
-            } else {
-                self.hours
-            };
-            parts.push(format!("{} {}", abs_hours, get_hour_suffix(abs_hours)));
+    fn write_postgres_time<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        let sign = if !self.is_time_interval_pos() {
+            "-"
+        } else {
+            ""
+        };
+        write!(
+            f,
+            "{}{:02}:{:02}:{:02}",
+            sign,
+            super::safe_abs_u64(self.hours),
+            super::safe_abs_u64(self.minutes),
+            super::safe_abs_u64(self.seconds)
+        )?;
+        if self.microseconds != 0 {
+            write!(f, ".{:06}", super::safe_abs_u64(self.microseconds))?;
         }
+        Ok(())
+    }
 
-        if self.minutes != 0 {
-            let abs_minutes = if self.minutes < 0 {
-                -self.minutes
-            } else {
-                self.minutes
-            };
-            parts.push(format!("{} {}", abs_minutes, get_min_suffix(abs_minutes)));
+    /// Writes a postgres_verbose compliant interval string.
+    pub fn write_postgres_verbose<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        let is_negative = !self.is_time_interval_pos() && self.has_negative();
+        f.write_str("@")?;
+        if self.is_zeroed() {
+            return f.write_str(" 0");
         }
-
+        write_verbose_part(f, self.years as i64, get_year_suffix)?;
+        write_verbose_part(f, self.months as i64, get_mon_suffix)?;
+        write_verbose_part(f, self.days as i64, get_day_suffix)?;
+        write_verbose_part(f, self.hours, get_hour_suffix)?;
+        write_verbose_part(f, self.minutes, get_min_suffix)?;
         if self.seconds != 0 || self.microseconds != 0 {
-            let abs_seconds = if self.seconds < 0 {
-                -self.seconds
-            } else {
-                self.seconds
-            };
-            let abs_micros = if self.microseconds < 0 {
-                -self.microseconds
-            } else {
-                self.microseconds
-            };
+            let abs_seconds = self.seconds.abs();
+            let abs_micros = self.microseconds.abs();
+            let suffix = get_sec_suffix(abs_seconds, abs_micros);
             if abs_micros != 0 {
                 let secs_with_micros = abs_seconds as f64 + abs_micros as f64 / 1_000_000.0;
-                parts.push(format!(
-                    "{} {}",
-                    secs_with_micros,
-                    get_sec_suffix(abs_seconds, abs_micros)
-                ));
+                write!(f, " {} {}", secs_with_micros, suffix)?;
             } else {
-                parts.push(format!(
-                    "{} {}",
-                    abs_seconds,
-                    get_sec_suffix(abs_seconds, abs_micros)
-                ));
+                write!(f, " {} {}", abs_seconds, suffix)?;
             }
         }
-
-        if parts.is_empty() {
-            return "@ 0".to_owned();
-        }
-
-        let result = format!("@ {}", parts.join(" "));
         if is_negative {
-            format!("{} ago", result)
-        } else {
-            result
+            f.write_str(" ago")?;
         }
+        Ok(())
     }
 }
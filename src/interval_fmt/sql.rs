@@ -1,30 +1,24 @@
 use crate::interval_norm::IntervalNorm;
+use std::fmt;
 
 impl IntervalNorm {
-    fn format_time(
-        hours: i64,
-        minutes: i64,
-        seconds: i64,
-        microseconds: i64,
-        sign: bool,
-    ) -> String {
-        let sign_str = if sign { "-" } else { "" };
-        let time_str = format!(
+    fn write_time<W: fmt::Write>(&self, f: &mut W, sign: &str) -> fmt::Result {
+        write!(
+            f,
             "{}{}:{:02}:{:02}",
-            sign_str,
-            super::safe_abs_u64(hours),
-            super::safe_abs_u64(minutes),
-            super::safe_abs_u64(seconds)
-        );
-
-        if microseconds != 0 {
-            format!("{}.{:06}", time_str, super::safe_abs_u64(microseconds))
-        } else {
-            time_str
+            sign,
+            super::safe_abs_u64(self.hours),
+            super::safe_abs_u64(self.minutes),
+            super::safe_abs_u64(self.seconds)
+        )?;
+        if self.microseconds != 0 {
+            write!(f, ".{:06}", super::safe_abs_u64(self.microseconds))?;
         }
+        Ok(())
     }
 
-    pub fn into_sql(self) -> String {
+    /// Writes a sql compliant interval string.
+    pub fn write_sql<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
         let has_negative = self.has_negative();
         let has_positive = self.has_positive();
 
@@ -34,7 +28,7 @@ impl IntervalNorm {
         let sql_standard_value = !(has_negative && has_positive || has_year_month && has_day_time);
 
         if !has_negative && !has_positive {
-            return "0".to_owned();
+            return f.write_str("0");
         }
 
         if !sql_standard_value {
@@ -49,56 +43,33 @@ impl IntervalNorm {
                 || self.seconds < 0
                 || self.microseconds < 0
             {
-                '-'
+                "-"
             } else {
-                '+'
+                "+"
             };
-
-            let time_str = format!(
-                "{}{}:{:02}:{:02}",
-                sec_sign,
-                super::safe_abs_u64(self.hours),
-                super::safe_abs_u64(self.minutes),
-                super::safe_abs_u64(self.seconds)
-            );
-
-            let time_str = if self.microseconds != 0 {
-                format!("{}.{:06}", time_str, super::safe_abs_u64(self.microseconds))
-            } else {
-                time_str
-            };
-
-            format!(
-                "{}{}-{} {}{} {}",
+            write!(
+                f,
+                "{}{}-{} {}{} ",
                 year_sign,
-                self.years.abs(),
-                self.months.abs(),
+                super::safe_abs_u32(self.years),
+                super::safe_abs_u32(self.months),
                 day_sign,
-                self.days.abs(),
-                time_str
-            )
+                super::safe_abs_u32(self.days),
+            )?;
+            self.write_time(f, sec_sign)
         } else if has_year_month {
-            format!("{}-{}", self.years, super::safe_abs_u32(self.months))
-        } else if self.days != 0 {
-            format!(
-                "{} {}",
-                self.days,
-                Self::format_time(
-                    self.hours,
-                    self.minutes,
-                    self.seconds,
-                    self.microseconds,
-                    false
-                )
+            write!(
+                f,
+                "{}{}-{}",
+                if has_negative { "-" } else { "" },
+                super::safe_abs_u32(self.years),
+                super::safe_abs_u32(self.months)
             )
+        } else if self.days != 0 {
+            write!(f, "{} ", self.days)?;
+            self.write_time(f, "")
         } else {
-            Self::format_time(
-                self.hours,
-                self.minutes,
-                self.seconds,
-                self.microseconds,
-                has_negative,
-            )
+            self.write_time(f, if has_negative { "-" } else { "" })
         }
     }
 }
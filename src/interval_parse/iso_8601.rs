@@ -1,7 +1,7 @@
 use super::parse_error::ParseError;
 use super::{
     DAYS_PER_MONTH, HOURS_PER_DAY, MICROS_PER_SECOND, MINUTES_PER_HOUR, MONTHS_PER_YEAR,
-    SECONDS_PER_MIN, scale_date, scale_time,
+    SECONDS_PER_MIN, parse_infinity, scale_date, scale_time,
 };
 use crate::{Interval, interval_norm::IntervalNorm};
 
@@ -12,10 +12,13 @@ enum ParserCode {
 }
 
 impl Interval {
+    /// Parses an ISO 8601 interval, either in the format with designators like
+    /// `P1Y2M3DT4H5M6S` or in the alternative format like `P0001-02-03T04:05:06`.
+    /// Like postgres the date and time parts can each use either format.
     pub fn from_iso(iso_str: &str) -> Result<Interval, ParseError> {
-        let mut date_part = true;
-        let delim = vec!['Y', 'M', 'D', 'H', 'S'];
-        let mut number = String::new();
+        if let Some(interval) = parse_infinity(iso_str) {
+            return Ok(interval);
+        }
         let mut interval_norm = IntervalNorm::default();
         if iso_str.rfind('P') == Some(1) {
             Err(ParseError::from_invalid_interval(
@@ -26,84 +29,207 @@ impl Interval {
                 "Invalid format length is less than 2.",
             ))
         } else {
-            for x in iso_str.chars() {
-                if x == 'P' {
-                    continue;
-                }
-                if x == 'T' && date_part {
-                    date_part = false;
-                    continue;
+            let (date, time) = match iso_str.split_once('T') {
+                Some((date, time)) => (date, Some(time)),
+                None => (iso_str, None),
+            };
+            match date.strip_prefix('P').filter(|date| is_alternative(date)) {
+                Some(date) => parse_alternative(date, '-', true, &mut interval_norm)?,
+                None => parse_designators(date, true, &mut interval_norm)?,
+            }
+            match time {
+                Some(time) if is_alternative(time) => {
+                    parse_alternative(time, ':', false, &mut interval_norm)?
                 }
-                let code = consume_number(&x, &mut number, &delim);
-                match code {
-                    ParserCode::BadFormat => {
-                        return Err(ParseError::from_invalid_interval("Invalid format."));
+                Some(time) => parse_designators(time, false, &mut interval_norm)?,
+                None => (),
+            }
+            interval_norm.try_into_interval()
+        }
+    }
+}
+
+/// Parses the date or time part of an interval in the format with designators.
+fn parse_designators(
+    part: &str,
+    date_part: bool,
+    interval_norm: &mut IntervalNorm,
+) -> Result<(), ParseError> {
+    let delim = vec!['Y', 'M', 'D', 'H', 'S'];
+    let mut number = String::new();
+    for x in part.chars() {
+        if x == 'P' {
+            continue;
+        }
+        let code = consume_number(&x, &mut number, &delim);
+        match code {
+            ParserCode::BadFormat => {
+                return Err(ParseError::from_invalid_interval("Invalid format."));
+            }
+            ParserCode::Good => {
+                continue;
+            }
+            ParserCode::DelimFound => {
+                let val = parse_number(&mut number, date_part)?;
+                match x {
+                    'Y' => {
+                        let (year, month) = scale_date(val, MONTHS_PER_YEAR);
+                        interval_norm.years += year;
+                        interval_norm.months += month;
+                    }
+                    'M' => {
+                        if date_part {
+                            let (month, day) = scale_date(val, DAYS_PER_MONTH);
+                            interval_norm.months += month;
+                            interval_norm.days += day;
+                        } else {
+                            let (minutes, seconds) = scale_time(val, SECONDS_PER_MIN);
+                            interval_norm.minutes += minutes;
+                            interval_norm.seconds += seconds;
+                        }
                     }
-                    ParserCode::Good => {
-                        continue;
+                    'D' => {
+                        let (days, hours) = scale_date(val, HOURS_PER_DAY);
+                        interval_norm.days += days;
+                        interval_norm.hours += hours as i64;
                     }
-                    ParserCode::DelimFound => {
-                        let val = parse_number(&mut number)?;
-                        match x {
-                            'Y' => {
-                                let (year, month) = scale_date(val, MONTHS_PER_YEAR);
-                                interval_norm.years += year;
-                                interval_norm.months += month;
-                            }
-                            'M' => {
-                                if date_part {
-                                    let (month, day) = scale_date(val, DAYS_PER_MONTH);
-                                    interval_norm.months += month;
-                                    interval_norm.days += day;
-                                } else {
-                                    let (minutes, seconds) = scale_time(val, SECONDS_PER_MIN);
-                                    interval_norm.minutes += minutes;
-                                    interval_norm.seconds += seconds;
-                                }
-                            }
-                            'D' => {
-                                let (days, hours) = scale_date(val, HOURS_PER_DAY);
-                                interval_norm.days += days;
-                                interval_norm.hours += hours as i64;
-                            }
-                            'H' => {
-                                let (hours, minutes) = scale_time(val, MINUTES_PER_HOUR);
-                                interval_norm.hours += hours;
-                                interval_norm.minutes += minutes;
-                            }
-                            'S' => {
-                                if date_part {
-                                    return Err(ParseError::from_invalid_interval(
-                                        "Cannot have S in date part.",
-                                    ));
-                                }
-                                let (seconds, microseconds) = scale_time(val, MICROS_PER_SECOND);
-                                interval_norm.seconds += seconds;
-                                interval_norm.microseconds += microseconds;
-                            }
-                            _ => {
-                                return Err(ParseError::from_invalid_interval(
-                                    "Invalid format unknown delimiter.",
-                                ));
-                            }
+                    'H' => {
+                        let (hours, minutes) = scale_time(val, MINUTES_PER_HOUR);
+                        interval_norm.hours += hours;
+                        interval_norm.minutes += minutes;
+                    }
+                    'S' => {
+                        if date_part {
+                            return Err(ParseError::from_invalid_interval(
+                                "Cannot have S in date part.",
+                            ));
                         }
+                        let (seconds, microseconds) = scale_time(val, MICROS_PER_SECOND);
+                        interval_norm.seconds += seconds;
+                        interval_norm.microseconds += microseconds;
+                    }
+                    _ => {
+                        return Err(ParseError::from_invalid_interval(
+                            "Invalid format unknown delimiter.",
+                        ));
                     }
                 }
             }
-            if !number.is_empty() {
-                Err(ParseError::from_invalid_interval(
-                    "Invalid format could not parse whole interval.",
-                ))
-            } else {
-                interval_norm.try_into_interval()
+        }
+    }
+    if !number.is_empty() {
+        Err(ParseError::from_invalid_interval(
+            "Invalid format could not parse whole interval.",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Is the date or time part in the alternative format, i.e. numbers without any
+/// designators?
+fn is_alternative(part: &str) -> bool {
+    !part.is_empty() && !part.contains(|x: char| x.is_ascii_alphabetic())
+}
+
+/// Parses the date part of an interval in the alternative format, the years, months
+/// and days separated by `-`, or the time part, the hours, minutes and seconds
+/// separated by `:`. Trailing fields can be left out and every field can have its
+/// own sign and a fraction like in the format with designators. Like postgres a
+/// single number of 8 digits in the date part or 6 digits in the time part is the
+/// basic format, i.e. `YYYYMMDD` or `HHMMSS`.
+fn parse_alternative(
+    part: &str,
+    separator: char,
+    date_part: bool,
+    interval_norm: &mut IntervalNorm,
+) -> Result<(), ParseError> {
+    let basic_width = if date_part { 8 } else { 6 };
+    let mut rest = part;
+    for field in 0..3 {
+        let sign_len = if rest.starts_with(['-', '+']) { 1 } else { 0 };
+        let len = rest[sign_len..]
+            .find(|x: char| !x.is_ascii_digit() && x != '.')
+            .map_or(rest.len(), |len| len + sign_len);
+        let mut number = rest[..len].to_owned();
+        if !number.contains(|x: char| x.is_ascii_digit()) {
+            return Err(ParseError::from_invalid_interval(
+                "Invalid alternative format missing number.",
+            ));
+        }
+        let integer_width = number[sign_len..]
+            .find('.')
+            .unwrap_or(number.len() - sign_len);
+        let val = parse_number(&mut number, date_part)?;
+        if field == 0 && len == rest.len() && integer_width == basic_width {
+            // the fraction belongs to the last field, the days or the seconds.
+            let whole = val.trunc() as i64;
+            let fields = [whole / 10_000, whole / 100 % 100, whole % 100];
+            for (field, whole) in fields.into_iter().enumerate() {
+                let fract = if field == 2 { val.fract() } else { 0.0 };
+                add_alternative_field(date_part, field, whole as f64 + fract, interval_norm);
             }
+            return Ok(());
+        }
+        add_alternative_field(date_part, field, val, interval_norm);
+        rest = &rest[len..];
+        if rest.is_empty() {
+            return Ok(());
+        }
+        rest = rest.strip_prefix(separator).ok_or_else(|| {
+            ParseError::from_invalid_interval("Invalid alternative format separator.")
+        })?;
+    }
+    Err(ParseError::from_invalid_interval(
+        "Invalid alternative format too many fields.",
+    ))
+}
+
+/// Adds the value of the alternative format's field, counting from the years in
+/// the date part or the hours in the time part.
+fn add_alternative_field(
+    date_part: bool,
+    field: usize,
+    val: f64,
+    interval_norm: &mut IntervalNorm,
+) {
+    match (date_part, field) {
+        (true, 0) => {
+            let (year, month) = scale_date(val, MONTHS_PER_YEAR);
+            interval_norm.years += year;
+            interval_norm.months += month;
+        }
+        (true, 1) => {
+            let (month, day) = scale_date(val, DAYS_PER_MONTH);
+            interval_norm.months += month;
+            interval_norm.days += day;
+        }
+        (true, _) => {
+            let (days, hours) = scale_date(val, HOURS_PER_DAY);
+            interval_norm.days += days;
+            interval_norm.hours += hours as i64;
+        }
+        (false, 0) => {
+            let (hours, minutes) = scale_time(val, MINUTES_PER_HOUR);
+            interval_norm.hours += hours;
+            interval_norm.minutes += minutes;
+        }
+        (false, 1) => {
+            let (minutes, seconds) = scale_time(val, SECONDS_PER_MIN);
+            interval_norm.minutes += minutes;
+            interval_norm.seconds += seconds;
+        }
+        (false, _) => {
+            let (seconds, microseconds) = scale_time(val, MICROS_PER_SECOND);
+            interval_norm.seconds += seconds;
+            interval_norm.microseconds += microseconds;
         }
     }
 }
 
 fn consume_number<'a>(val: &'a char, number: &'a mut String, delim: &[char]) -> ParserCode {
     let is_first_char = number.is_empty() && *val == '-';
-    let is_period_char = !number.is_empty() && *val == '.';
+    let is_period_char = *val == '.' && !number.contains('.');
     if val.is_ascii_digit() || is_first_char || is_period_char {
         number.push(*val);
         ParserCode::Good
@@ -114,9 +240,16 @@ fn consume_number<'a>(val: &'a char, number: &'a mut String, delim: &[char]) ->
     }
 }
 
-fn parse_number(number: &mut String) -> Result<f64, ParseError> {
+/// Parses the number before a delimiter, the date units are limited to 32 bits
+/// while the time units can use the full 64 bits of the microseconds.
+fn parse_number(number: &mut String, date_part: bool) -> Result<f64, ParseError> {
     let parse_num = number.parse::<f64>()?;
-    if parse_num > i32::MAX as f64 {
+    let (min, max) = if date_part {
+        (i32::MIN as f64, i32::MAX as f64)
+    } else {
+        (i64::MIN as f64, i64::MAX as f64)
+    };
+    if parse_num < min || parse_num > max {
         Err(ParseError::from_invalid_interval("Exceeded max value"))
     } else {
         *number = "".to_owned();
@@ -128,6 +261,12 @@ fn parse_number(number: &mut String) -> Result<f64, ParseError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_iso_infinity() {
+        assert_eq!(Interval::from_iso("infinity"), Ok(Interval::INFINITY));
+        assert_eq!(Interval::from_iso("-infinity"), Ok(Interval::NEG_INFINITY));
+    }
+
     #[test]
     fn test_from_iso_1() {
         let interval = Interval::from_iso("P1Y").unwrap();
@@ -268,8 +407,11 @@ mod tests {
 
     #[test]
     fn test_from_8601_21() {
-        let interval = Interval::from_iso("PT10");
-        assert!(interval.is_err());
+        // hours in the alternative format, like postgres reads it.
+        let interval = Interval::from_iso("PT10").unwrap();
+        let interval_exp = Interval::new(0, 0, 36_000_000_000);
+        assert_eq!(interval, interval_exp);
+        assert!(Interval::from_iso("PT10H5").is_err());
     }
 
     #[test]
@@ -299,4 +441,104 @@ mod tests {
         let interval_exp = Interval::new(0, 0, 10000000);
         assert_eq!(interval, interval_exp);
     }
+
+    #[test]
+    fn test_from_8601_alternative() {
+        let interval = Interval::from_iso("P0001-02-03T04:05:06").unwrap();
+        let interval_exp = Interval::new(14, 3, 14_706_000_000);
+        assert_eq!(interval, interval_exp);
+    }
+
+    #[test]
+    fn test_from_8601_alternative_partial() {
+        assert_eq!(Interval::from_iso("P0001"), Ok(Interval::new(12, 0, 0)));
+        assert_eq!(Interval::from_iso("P0001-02"), Ok(Interval::new(14, 0, 0)));
+        assert_eq!(
+            Interval::from_iso("P0001-02-03"),
+            Ok(Interval::new(14, 3, 0))
+        );
+        assert_eq!(
+            Interval::from_iso("PT04:05"),
+            Ok(Interval::new(0, 0, 14_700_000_000))
+        );
+        assert_eq!(
+            Interval::from_iso("P0000-00-00T00:00:00"),
+            Ok(Interval::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_from_8601_alternative_fractional() {
+        let interval = Interval::from_iso("PT00:00:01.5").unwrap();
+        assert_eq!(interval, Interval::new(0, 0, 1_500_000));
+        let interval = Interval::from_iso("P0000-00-00T00:00:00.000001").unwrap();
+        assert_eq!(interval, Interval::new(0, 0, 1));
+        let interval = Interval::from_iso("P0001.5-00-00").unwrap();
+        assert_eq!(interval, Interval::new(18, 0, 0));
+    }
+
+    #[test]
+    fn test_from_8601_alternative_negative() {
+        let interval = Interval::from_iso("P-0001--02--03T-04:-05:-06.5").unwrap();
+        let interval_exp = Interval::new(-14, -3, -14_706_500_000);
+        assert_eq!(interval, interval_exp);
+        let interval = Interval::from_iso("P-0001-02-+03T04:-05:06").unwrap();
+        let interval_exp = Interval::new(-10, 3, 14_106_000_000);
+        assert_eq!(interval, interval_exp);
+    }
+
+    #[test]
+    fn test_from_8601_alternative_mixed() {
+        let interval = Interval::from_iso("P1Y2M3DT04:05:06").unwrap();
+        assert_eq!(interval, Interval::new(14, 3, 14_706_000_000));
+        let interval = Interval::from_iso("P0001-02-03T4H5M6S").unwrap();
+        assert_eq!(interval, Interval::new(14, 3, 14_706_000_000));
+    }
+
+    #[test]
+    fn test_from_8601_basic() {
+        // interval 'P00010203' = 1 year 2 mons 3 days
+        let interval = Interval::from_iso("P00010203").unwrap();
+        assert_eq!(interval, Interval::new(14, 3, 0));
+        // interval 'PT040506' = 04:05:06
+        let interval = Interval::from_iso("PT040506").unwrap();
+        assert_eq!(interval, Interval::new(0, 0, 14_706_000_000));
+        let interval = Interval::from_iso("P00010203T040506").unwrap();
+        assert_eq!(interval, Interval::new(14, 3, 14_706_000_000));
+    }
+
+    #[test]
+    fn test_from_8601_basic_sign_and_fraction() {
+        let interval = Interval::from_iso("P-00010203T-040506.5").unwrap();
+        assert_eq!(interval, Interval::new(-14, -3, -14_706_500_000));
+        let interval = Interval::from_iso("P00000000.5").unwrap();
+        assert_eq!(interval, Interval::new(0, 0, 12 * 3_600_000_000));
+        // other widths keep the first number as the years or hours.
+        assert_eq!(
+            Interval::from_iso("P0010203"),
+            Ok(Interval::new(10_203 * 12, 0, 0))
+        );
+        assert_eq!(
+            Interval::from_iso("PT40506"),
+            Ok(Interval::new(0, 0, 40_506 * 3_600_000_000))
+        );
+    }
+
+    #[test]
+    fn test_from_8601_alternative_invalid() {
+        for input in [
+            "P0001-02-03-04",
+            "PT04:05:06:07",
+            "P0001--",
+            "P0001-02-",
+            "PT04::06",
+            "P0001/02/03",
+            "PT04-05",
+            "P-",
+            "P1.2.3",
+            "P99999999999",
+        ] {
+            assert!(Interval::from_iso(input).is_err(), "{}", input);
+        }
+    }
 }
@@ -1,3 +1,7 @@
+use crate::{Interval, IntervalStyle};
+use parse_error::ParseError;
+use std::str::FromStr;
+
 mod iso_8601;
 pub mod parse_error;
 mod postgres;
@@ -10,6 +14,68 @@ static HOURS_PER_DAY: i32 = 24;
 static MINUTES_PER_HOUR: i32 = 60;
 static MICROS_PER_SECOND: i32 = 1_000_000;
 
+/// Postgres reads and writes infinite intervals the same way in every interval style.
+fn parse_infinity(input: &str) -> Option<Interval> {
+    match &*input.trim().to_lowercase() {
+        "infinity" | "+infinity" => Some(Interval::INFINITY),
+        "-infinity" => Some(Interval::NEG_INFINITY),
+        _ => None,
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseError;
+
+    /// Parses the interval, detecting which of the supported styles it is in. ISO 8601
+    /// starts with `P`, `postgres_verbose` with `@` and the `postgres` style has unit
+    /// words, while the `sql_standard` style is only numbers. A plain time like
+    /// `04:05:06` is written the same way in both the `sql_standard` and `postgres`
+    /// styles, so when the `sql_standard` style fails the `postgres` style is tried too.
+    fn from_str(value: &str) -> Result<Interval, ParseError> {
+        let value = value.trim();
+        if value.is_empty() {
+            Err(ParseError::from_invalid_interval(
+                "Interval can not be empty.",
+            ))
+        } else if value.starts_with('P') {
+            Interval::from_iso(value)
+        } else if value.starts_with('@') {
+            Interval::from_postgres_verbose(value)
+        } else if value.contains(char::is_alphabetic) {
+            Interval::from_postgres(value)
+        } else {
+            Interval::from_sql(value).or_else(|sql_error| {
+                Interval::from_postgres(value).map_err(|postgres_error| {
+                    ParseError::from_invalid_interval(&format!(
+                        "assumed sql_standard style ({}), postgres style also failed ({})",
+                        sql_error, postgres_error
+                    ))
+                })
+            })
+        }
+    }
+}
+
+impl Interval {
+    /// Parses the interval in the style, without detecting it like `from_str` does.
+    pub fn parse_with_style(value: &str, style: IntervalStyle) -> Result<Interval, ParseError> {
+        match style {
+            IntervalStyle::Postgres => Interval::from_postgres(value),
+            IntervalStyle::PostgresVerbose => Interval::from_postgres_verbose(value),
+            IntervalStyle::SqlStandard => Interval::from_sql(value),
+            IntervalStyle::Iso8601 => Interval::from_iso(value),
+        }
+    }
+}
+
+impl TryFrom<&str> for Interval {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Interval, ParseError> {
+        value.parse()
+    }
+}
+
 fn scale_date(val: f64, scale: i32) -> (i32, i32) {
     if val.fract() == 0.0 {
         (val.trunc() as i32, 0)
@@ -29,3 +95,74 @@ fn scale_time(val: f64, scale: i32) -> (i64, i64) {
         (val.trunc() as i64, sub_value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3_600_000_000;
+
+    #[test]
+    fn test_from_str_detects_style() {
+        let expected = Interval::new(14, 3, 4 * HOUR);
+        for input in [
+            "1 year 2 mons 3 days 04:00:00",
+            "P1Y2M3DT4H",
+            "P0001-02-03T04:00:00",
+            "+1-2 +3 +4:00:00",
+            "1-2 3 4:00:00",
+            "@ 1 year 2 mons 3 days 4 hours",
+            "  P1Y2M3DT4H ",
+        ] {
+            assert_eq!(input.parse::<Interval>(), Ok(expected), "{}", input);
+            assert_eq!(Interval::try_from(input), Ok(expected), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_from_str_time() {
+        let expected = Interval::new(0, 0, 4 * HOUR + 5_000_000);
+        assert_eq!("04:00:05".parse(), Ok(expected));
+        assert_eq!("-04:00:05".parse(), Ok(-expected));
+    }
+
+    #[test]
+    fn test_from_str_infinity() {
+        assert_eq!("infinity".parse(), Ok(Interval::INFINITY));
+        assert_eq!(" -infinity".parse(), Ok(Interval::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_from_str_reports_detected_style() {
+        assert_eq!(
+            "P1X".parse::<Interval>().unwrap_err(),
+            Interval::from_iso("P1X").unwrap_err()
+        );
+        assert_eq!(
+            "@ 1 fortnight".parse::<Interval>().unwrap_err(),
+            Interval::from_postgres_verbose("@ 1 fortnight").unwrap_err()
+        );
+        assert_eq!(
+            "1 fortnight".parse::<Interval>().unwrap_err(),
+            Interval::from_postgres("1 fortnight").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_from_str_ambiguous_error() {
+        let error = "1-2 3 4".parse::<Interval>().unwrap_err();
+        let message = error.to_string();
+        assert!(
+            message.contains("assumed sql_standard style")
+                && message.contains("postgres style also failed"),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    fn test_from_str_empty() {
+        assert!("".parse::<Interval>().is_err());
+        assert!("   ".parse::<Interval>().is_err());
+    }
+}
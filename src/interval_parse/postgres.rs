@@ -1,13 +1,16 @@
 use super::parse_error::ParseError;
-use crate::{Interval, interval_norm::IntervalNorm};
+use crate::{Interval, IntervalFields, IntervalTypmod, interval_norm::IntervalNorm};
 
 use super::{
     DAYS_PER_MONTH, HOURS_PER_DAY, MICROS_PER_SECOND, MINUTES_PER_HOUR, MONTHS_PER_YEAR,
-    SECONDS_PER_MIN, scale_date, scale_time,
+    SECONDS_PER_MIN, parse_infinity, scale_date, scale_time,
 };
 
 impl Interval {
     pub fn from_postgres_verbose(verbose_str: &str) -> Result<Interval, ParseError> {
+        if let Some(interval) = parse_infinity(verbose_str) {
+            return Ok(interval);
+        }
         let mut is_negative = false;
         let mut input = verbose_str.trim();
 
@@ -90,6 +93,29 @@ impl Interval {
     }
 
     pub fn from_postgres(iso_str: &str) -> Result<Interval, ParseError> {
+        Interval::parse_postgres(iso_str, None)
+    }
+
+    /// Parses the postgres style interval the way postgres reads it for a column
+    /// with the typmod. A number without a unit is read as the rightmost field of
+    /// the typmod, or seconds when unrestricted, with any earlier ones read as days.
+    /// The result is then adjusted to the typmod using `apply_typmod`.
+    pub fn from_postgres_with_typmod(
+        iso_str: &str,
+        typmod: IntervalTypmod,
+    ) -> Result<Interval, ParseError> {
+        Interval::parse_postgres(iso_str, Some(typmod))?
+            .checked_apply_typmod(typmod)
+            .ok_or_else(|| ParseError::from_invalid_interval("Interval out of range."))
+    }
+
+    fn parse_postgres(
+        iso_str: &str,
+        typmod: Option<IntervalTypmod>,
+    ) -> Result<Interval, ParseError> {
+        if let Some(interval) = parse_infinity(iso_str) {
+            return Ok(interval);
+        }
         let mut delim = vec![
             "years", "year", "months", "mons", "mon", "days", "day", "hours", "hour", "minutes",
             "minute", "seconds", "second",
@@ -124,6 +150,9 @@ impl Interval {
                 final_tokens.push(token.to_owned());
             }
         }
+        if let Some(typmod) = typmod {
+            final_tokens = add_implicit_units(final_tokens, typmod.fields());
+        }
         if final_tokens.len() % 2 != 0 {
             return Err(ParseError::from_invalid_interval(
                 "Invalid amount tokens were found.",
@@ -147,6 +176,26 @@ impl Interval {
     }
 }
 
+/// Gives each number without a unit the unit postgres reads it as, the rightmost
+/// field of the typmod for the last one and days for any others.
+fn add_implicit_units(tokens: Vec<String>, fields: Option<IntervalFields>) -> Vec<String> {
+    let mut result = Vec::with_capacity(tokens.len() * 2);
+    let mut last_implicit = None;
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        let is_number = token.parse::<f64>().is_ok();
+        result.push(token);
+        if is_number && tokens.peek().is_none_or(|next| next.parse::<f64>().is_ok()) {
+            last_implicit = Some(result.len());
+            result.push("days".to_owned());
+        }
+    }
+    if let Some(index) = last_implicit {
+        result[index] = IntervalFields::unit(fields).to_owned();
+    }
+    result
+}
+
 /// Does the token contain both alphabetic and numeric characters?
 fn is_token_alphanumeric(val: &str) -> Result<bool, ParseError> {
     let mut has_numeric = false;
@@ -336,6 +385,63 @@ fn consume_token(
 mod tests {
     use super::*;
 
+    const HOUR: i64 = 3_600_000_000;
+    const MINUTE: i64 = 60_000_000;
+
+    #[test]
+    fn test_from_postgres_with_typmod_bare_numbers() {
+        // interval '1 2' hour to minute = '1 day 00:02:00'
+        let interval =
+            Interval::from_postgres_with_typmod("1 2", IntervalFields::HourToMinute.into());
+        assert_eq!(interval, Ok(Interval::new(0, 1, 2 * MINUTE)));
+        // interval '3 mons 2' day = '3 mons 2 days'
+        let interval = Interval::from_postgres_with_typmod("3 mons 2", IntervalFields::Day.into());
+        assert_eq!(interval, Ok(Interval::new(3, 2, 0)));
+        // interval '10' = '00:00:10'
+        let interval = Interval::from_postgres_with_typmod("10", IntervalTypmod::UNRESTRICTED);
+        assert_eq!(interval, Ok(Interval::new(0, 0, 10_000_000)));
+    }
+
+    #[test]
+    fn test_from_postgres_with_typmod_adjusts() {
+        // interval '1 year 2 mons 3 days 04:05:06.789' day to hour(0)
+        let typmod = IntervalTypmod::new(Some(IntervalFields::DayToHour), Some(0)).unwrap();
+        let interval =
+            Interval::from_postgres_with_typmod("1 year 2 mons 3 days 04:05:06.789", typmod);
+        assert_eq!(interval, Ok(Interval::new(14, 3, 4 * HOUR)));
+        let interval = Interval::from_postgres_with_typmod("1 2 3", IntervalTypmod::UNRESTRICTED);
+        assert!(interval.is_err());
+    }
+
+    #[test]
+    fn test_from_postgres_with_typmod_infinity() {
+        let interval = Interval::from_postgres_with_typmod("infinity", IntervalFields::Year.into());
+        assert_eq!(interval, Ok(Interval::INFINITY));
+    }
+
+    #[test]
+    fn test_from_postgres_infinity() {
+        assert_eq!(Interval::from_postgres("infinity"), Ok(Interval::INFINITY));
+        assert_eq!(Interval::from_postgres("+infinity"), Ok(Interval::INFINITY));
+        assert_eq!(
+            Interval::from_postgres(" -Infinity "),
+            Ok(Interval::NEG_INFINITY)
+        );
+        assert!(Interval::from_postgres("infinityy").is_err());
+    }
+
+    #[test]
+    fn test_from_postgres_verbose_infinity() {
+        assert_eq!(
+            Interval::from_postgres_verbose("infinity"),
+            Ok(Interval::INFINITY)
+        );
+        assert_eq!(
+            Interval::from_postgres_verbose("-infinity"),
+            Ok(Interval::NEG_INFINITY)
+        );
+    }
+
     #[test]
     fn test_from_postgres_1() {
         let interval = Interval::from_postgres("1 years").unwrap();
@@ -1017,7 +1123,7 @@ mod tests {
     #[test]
     fn test_from_postgres_verbose_invalid_no_at() {
         let interval = Interval::from_postgres_verbose("1 year");
-        assert_eq!(interval.is_err(), true);
+        assert!(interval.is_err());
     }
 
     #[test]
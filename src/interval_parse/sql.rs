@@ -1,21 +1,63 @@
 use super::parse_error::ParseError;
-use crate::Interval;
+use super::{
+    DAYS_PER_MONTH, HOURS_PER_DAY, MICROS_PER_SECOND, MINUTES_PER_HOUR, MONTHS_PER_YEAR,
+    SECONDS_PER_MIN, parse_infinity, scale_date, scale_time,
+};
 use crate::interval_norm::IntervalNorm;
+use crate::{Interval, IntervalFields, IntervalTypmod};
 
 impl Interval {
     pub fn from_sql(sql_str: &str) -> Result<Interval, ParseError> {
+        Interval::parse_sql(sql_str, None)
+    }
+
+    /// Parses the sql standard interval the way postgres reads it for a column with
+    /// the typmod. A number without a unit is read as the rightmost field of the
+    /// typmod, or seconds when unrestricted, and for `MINUTE TO SECOND` a time with
+    /// two parts is read as minutes and seconds. The result is then adjusted to the
+    /// typmod using `apply_typmod`.
+    pub fn from_sql_with_typmod(
+        sql_str: &str,
+        typmod: IntervalTypmod,
+    ) -> Result<Interval, ParseError> {
+        Interval::parse_sql(sql_str, Some(typmod))?
+            .checked_apply_typmod(typmod)
+            .ok_or_else(|| ParseError::from_invalid_interval("Interval out of range."))
+    }
+
+    fn parse_sql(sql_str: &str, typmod: Option<IntervalTypmod>) -> Result<Interval, ParseError> {
+        if let Some(interval) = parse_infinity(sql_str) {
+            return Ok(interval);
+        }
         if sql_str == "0" {
             return Ok(Interval::new(0, 0, 0));
         }
 
-        let tokens: Vec<&str> = sql_str.split_whitespace().collect();
+        // like postgres a leading minus makes every field without its own sign
+        // negative, unless any of the other fields has its own sign.
+        let mut words = sql_str.split_whitespace();
+        let is_negative = words.next().is_some_and(|token| token.starts_with('-'))
+            && !words.any(|token| token.starts_with(['-', '+']));
+        let tokens: Vec<String> = sql_str
+            .split_whitespace()
+            .map(|token| match token.chars().next() {
+                Some('-' | '+') => token.to_owned(),
+                _ if is_negative => format!("-{}", token),
+                _ => token.to_owned(),
+            })
+            .collect();
+        let tokens: Vec<&str> = tokens.iter().map(String::as_str).collect();
         let mut interval_norm = IntervalNorm::default();
+        let fields = typmod.and_then(|typmod| typmod.fields());
+        let minutes_to_seconds = fields == Some(IntervalFields::MinuteToSecond);
 
         match tokens.len() {
             1 => {
                 let token = tokens[0];
-                if token.contains(':') {
-                    parse_time_part(token, &mut interval_norm, true)?;
+                if typmod.is_some() && is_number(token) {
+                    parse_number_part(token, fields, &mut interval_norm)?;
+                } else if token.contains(':') {
+                    parse_time_part(token, &mut interval_norm, true, minutes_to_seconds)?;
                 } else if token.contains('-') {
                     parse_year_month_part(token, &mut interval_norm)?;
                 } else {
@@ -26,13 +68,17 @@ impl Interval {
             }
             2 => {
                 parse_day_part(tokens[0], &mut interval_norm)?;
-                parse_time_part(tokens[1], &mut interval_norm, true)?;
+                if typmod.is_some() && is_number(tokens[1]) {
+                    parse_number_part(tokens[1], fields, &mut interval_norm)?;
+                } else {
+                    parse_time_part(tokens[1], &mut interval_norm, true, minutes_to_seconds)?;
+                }
             }
             3 => {
                 // Mixed format: year-month + day + time
                 parse_year_month_part(tokens[0], &mut interval_norm)?;
                 parse_day_part(tokens[1], &mut interval_norm)?;
-                parse_time_part(tokens[2], &mut interval_norm, false)?;
+                parse_time_part(tokens[2], &mut interval_norm, false, minutes_to_seconds)?;
             }
             _ => {
                 return Err(ParseError::from_invalid_interval(
@@ -74,16 +120,58 @@ fn parse_year_month_part(token: &str, interval: &mut IntervalNorm) -> Result<(),
 }
 
 fn parse_day_part(token: &str, interval: &mut IntervalNorm) -> Result<(), ParseError> {
-    // Handle optional leading sign
-    let (sign, days_str) = if let Some(rest) = token.strip_prefix('-') {
-        (-1, rest)
-    } else if let Some(rest) = token.strip_prefix('+') {
-        (1, rest)
-    } else {
-        (1, token)
-    };
-    let days: i32 = days_str.parse()?;
-    interval.days = days * sign;
+    interval.days = token.parse()?;
+    Ok(())
+}
+
+/// Is the token a plain number without a unit?
+fn is_number(token: &str) -> bool {
+    token
+        .chars()
+        .all(|character| character.is_ascii_digit() || matches!(character, '.' | '-' | '+'))
+        && token.parse::<f64>().is_ok()
+}
+
+/// Reads the number as the rightmost field of the typmod, postgres cascades any
+/// fraction down into the next smaller units.
+fn parse_number_part(
+    token: &str,
+    fields: Option<IntervalFields>,
+    interval: &mut IntervalNorm,
+) -> Result<(), ParseError> {
+    let val = token.parse::<f64>()?;
+    match IntervalFields::unit(fields) {
+        "years" => {
+            let (years, months) = scale_date(val, MONTHS_PER_YEAR);
+            interval.years += years;
+            interval.months += months;
+        }
+        "months" => {
+            let (months, days) = scale_date(val, DAYS_PER_MONTH);
+            interval.months += months;
+            interval.days += days;
+        }
+        "days" => {
+            let (days, hours) = scale_date(val, HOURS_PER_DAY);
+            interval.days += days;
+            interval.hours += hours as i64;
+        }
+        "hours" => {
+            let (hours, minutes) = scale_time(val, MINUTES_PER_HOUR);
+            interval.hours += hours;
+            interval.minutes += minutes;
+        }
+        "minutes" => {
+            let (minutes, seconds) = scale_time(val, SECONDS_PER_MIN);
+            interval.minutes += minutes;
+            interval.seconds += seconds;
+        }
+        _ => {
+            let (seconds, microseconds) = scale_time(val, MICROS_PER_SECOND);
+            interval.seconds += seconds;
+            interval.microseconds += microseconds;
+        }
+    }
     Ok(())
 }
 
@@ -91,6 +179,7 @@ fn parse_time_part(
     token: &str,
     interval: &mut IntervalNorm,
     is_only_time: bool,
+    minutes_to_seconds: bool,
 ) -> Result<(), ParseError> {
     let (time_token, sign) = if is_only_time {
         let is_negative = token.starts_with('-');
@@ -108,14 +197,20 @@ fn parse_time_part(
         return Err(ParseError::from_invalid_interval("Invalid time format"));
     }
 
-    let hours: i64 = time_parts[0].parse()?;
-    let minutes: i64 = time_parts[1].parse()?;
+    let mut hours: i64 = time_parts[0].parse()?;
+    let mut minutes: i64 = time_parts[1].parse()?;
 
-    let (seconds, microseconds) = if time_parts.len() == 3 {
+    let (mut seconds, microseconds) = if time_parts.len() == 3 {
         parse_seconds_part(time_parts[2])?
     } else {
         (0, 0)
     };
+    // a MINUTE TO SECOND interval reads two parts as mm:ss.
+    if minutes_to_seconds && time_parts.len() == 2 {
+        seconds = minutes;
+        minutes = hours;
+        hours = 0;
+    }
 
     interval.hours = hours * sign;
     interval.minutes = minutes * sign;
@@ -155,6 +250,105 @@ fn parse_seconds_part(token: &str) -> Result<(i64, i64), ParseError> {
 mod tests {
     use super::*;
 
+    const HOUR: i64 = 3_600_000_000;
+    const MINUTE: i64 = 60_000_000;
+
+    fn from_sql_with_fields(sql_str: &str, fields: IntervalFields) -> Interval {
+        Interval::from_sql_with_typmod(sql_str, fields.into()).unwrap()
+    }
+
+    #[test]
+    fn test_from_sql_with_typmod_bare_number() {
+        // interval '1' year = '1 year'
+        assert_eq!(
+            from_sql_with_fields("1", IntervalFields::Year),
+            Interval::new(12, 0, 0)
+        );
+        // interval '1' year to month = '1 mon'
+        assert_eq!(
+            from_sql_with_fields("1", IntervalFields::YearToMonth),
+            Interval::new(1, 0, 0)
+        );
+        // interval '4' hour = '04:00:00'
+        assert_eq!(
+            from_sql_with_fields("4", IntervalFields::Hour),
+            Interval::new(0, 0, 4 * HOUR)
+        );
+        // interval '6' = '00:00:06'
+        assert_eq!(
+            Interval::from_sql_with_typmod("6", IntervalTypmod::UNRESTRICTED),
+            Ok(Interval::new(0, 0, 6_000_000))
+        );
+    }
+
+    #[test]
+    fn test_from_sql_with_typmod_day_and_number() {
+        // interval '1 2' day to hour = '1 day 02:00:00'
+        assert_eq!(
+            from_sql_with_fields("1 2", IntervalFields::DayToHour),
+            Interval::new(0, 1, 2 * HOUR)
+        );
+        // interval '1 2' day to minute = '1 day 00:02:00'
+        assert_eq!(
+            from_sql_with_fields("1 2", IntervalFields::DayToMinute),
+            Interval::new(0, 1, 2 * MINUTE)
+        );
+        // interval '1 2' day to second = '1 day 00:00:02'
+        assert_eq!(
+            from_sql_with_fields("1 2", IntervalFields::DayToSecond),
+            Interval::new(0, 1, 2_000_000)
+        );
+    }
+
+    #[test]
+    fn test_from_sql_with_typmod_truncates_fields() {
+        // interval '1 2:03:04' day to hour = '1 day 02:00:00'
+        assert_eq!(
+            from_sql_with_fields("1 2:03:04", IntervalFields::DayToHour),
+            Interval::new(0, 1, 2 * HOUR)
+        );
+        // interval '1-2' year = '1 year'
+        assert_eq!(
+            from_sql_with_fields("1-2", IntervalFields::Year),
+            Interval::new(12, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_from_sql_with_typmod_minute_to_second() {
+        // interval '1 2:03' minute to second = '1 day 00:02:03'
+        assert_eq!(
+            from_sql_with_fields("1 2:03", IntervalFields::MinuteToSecond),
+            Interval::new(0, 1, 2 * MINUTE + 3_000_000)
+        );
+        // interval '1 2:03' hour to second = '1 day 02:03:00'
+        assert_eq!(
+            from_sql_with_fields("1 2:03", IntervalFields::HourToSecond),
+            Interval::new(0, 1, 2 * HOUR + 3 * MINUTE)
+        );
+    }
+
+    #[test]
+    fn test_from_sql_with_typmod_precision() {
+        // interval '1 2.345' day to second(2) = '1 day 00:00:02.35'
+        let typmod = IntervalTypmod::new(Some(IntervalFields::DayToSecond), Some(2)).unwrap();
+        assert_eq!(
+            Interval::from_sql_with_typmod("1 2.345", typmod),
+            Ok(Interval::new(0, 1, 2_350_000))
+        );
+    }
+
+    #[test]
+    fn test_from_sql_bare_number_needs_typmod() {
+        assert!(Interval::from_sql("1 2").is_err());
+    }
+
+    #[test]
+    fn test_from_sql_infinity() {
+        assert_eq!(Interval::from_sql("infinity"), Ok(Interval::INFINITY));
+        assert_eq!(Interval::from_sql("-infinity"), Ok(Interval::NEG_INFINITY));
+    }
+
     #[test]
     fn test_from_sql_1() {
         let interval = Interval::from_sql("1-0").unwrap();
@@ -281,6 +475,31 @@ mod tests {
         assert_eq!(interval, interval_exp);
     }
 
+    #[test]
+    fn test_from_sql_leading_minus() {
+        let interval = Interval::from_sql("-1 2:03:04").unwrap();
+        let interval_exp = Interval::new(0, -1, -7384000000);
+        assert_eq!(interval, interval_exp);
+        let interval = Interval::from_sql("-1-2 3 4:05:06").unwrap();
+        let interval_exp = Interval::new(-14, -3, -14706000000);
+        assert_eq!(interval, interval_exp);
+        // another signed field turns it off, like postgres' more_signs check.
+        let interval = Interval::from_sql("-1-2 3 +4:05:06").unwrap();
+        let interval_exp = Interval::new(-14, 3, 14706000000);
+        assert_eq!(interval, interval_exp);
+        let interval = Interval::from_sql("-1 -2:03:04").unwrap();
+        let interval_exp = Interval::new(0, -1, -7384000000);
+        assert_eq!(interval, interval_exp);
+        let interval = Interval::from_sql("-0-1").unwrap();
+        assert_eq!(interval, Interval::new(-1, 0, 0));
+    }
+
+    #[test]
+    fn test_from_sql_min_days() {
+        let interval = Interval::from_sql("-2147483648 0:00:00").unwrap();
+        assert_eq!(interval, Interval::new(0, i32::MIN, 0));
+    }
+
     #[test]
     fn test_from_sql_19() {
         let interval = Interval::from_sql("0").unwrap();
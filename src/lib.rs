@@ -1,11 +1,32 @@
-#[cfg(feature = "postgres")]
 mod integrations;
-
+mod interval_arithmetic;
+mod interval_duration;
 mod interval_fmt;
 mod interval_norm;
 mod interval_parse;
 mod pg_interval;
 mod pg_interval_add;
+mod pg_interval_age;
+mod pg_interval_cmp;
+mod pg_interval_extract;
+mod pg_interval_justify;
+mod pg_interval_make;
+mod pg_interval_mul;
 mod pg_interval_sub;
+mod pg_interval_trunc;
+mod pg_interval_typmod;
+pub use crate::integrations::conversion_error::ConversionError;
+#[cfg(feature = "prost")]
+pub use crate::integrations::prost::proto;
+#[cfg(feature = "serde")]
+pub use crate::integrations::serde;
+pub use crate::interval_arithmetic::IntervalArithmetic;
+pub use crate::interval_duration::{DurationPolicy, RoundingMode};
+pub use crate::interval_fmt::{IntervalDisplay, IntervalStyle};
 pub use crate::interval_parse::parse_error::ParseError;
 pub use crate::pg_interval::Interval;
+pub use crate::pg_interval_age::IntervalTimestamp;
+pub use crate::pg_interval_cmp::PgOrd;
+pub use crate::pg_interval_extract::{IntervalField, IntervalNumeric};
+pub use crate::pg_interval_make::MakeInterval;
+pub use crate::pg_interval_typmod::{IntervalFields, IntervalTypmod};
@@ -1,6 +1,28 @@
+use crate::IntervalStyle;
 use crate::interval_norm::IntervalNorm;
 
+/// The number of days postgres assumes are in a month when it has to
+/// convert between the two units.
+pub(crate) const DAYS_PER_MONTH: i32 = 30;
+/// The number of microseconds postgres assumes are in a day when it has
+/// to convert between the two units.
+pub(crate) const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+pub(crate) const MONTHS_PER_YEAR: i32 = 12;
+pub(crate) const DAYS_PER_WEEK: i32 = 7;
+pub(crate) const SECS_PER_DAY: i64 = 86_400;
+pub(crate) const MICROS_PER_HOUR: i64 = 3_600_000_000;
+pub(crate) const MICROS_PER_MINUTE: i64 = 60_000_000;
+pub(crate) const MICROS_PER_SECOND: i64 = 1_000_000;
+pub(crate) const MICROS_PER_MILLI: i64 = 1_000;
+pub(crate) const NANOS_PER_MICRO: i64 = 1_000;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Interval))]
 pub struct Interval {
     pub months: i32,
     pub days: i32,
@@ -8,8 +30,31 @@ pub struct Interval {
 }
 
 impl Interval {
+    /// The interval postgres represents as `infinity`.
+    pub const INFINITY: Interval = Interval {
+        months: i32::MAX,
+        days: i32::MAX,
+        microseconds: i64::MAX,
+    };
+
+    /// The interval postgres represents as `-infinity`.
+    pub const NEG_INFINITY: Interval = Interval {
+        months: i32::MIN,
+        days: i32::MIN,
+        microseconds: i64::MIN,
+    };
+
+    /// An interval of zero length.
+    pub const ZERO: Interval = Interval::new(0, 0, 0);
+
+    /// The largest finite interval, just below `infinity`.
+    pub const MAX: Interval = Interval::new(i32::MAX, i32::MAX, i64::MAX - 1);
+
+    /// The smallest finite interval, just above `-infinity`.
+    pub const MIN: Interval = Interval::new(i32::MIN, i32::MIN, i64::MIN + 1);
+
     /// Create a new instance of interval from the months, days, and microseconds.
-    pub fn new(months: i32, days: i32, microseconds: i64) -> Interval {
+    pub const fn new(months: i32, days: i32, microseconds: i64) -> Interval {
         Interval {
             months,
             days,
@@ -17,24 +62,125 @@ impl Interval {
         }
     }
 
+    /// Create an interval of the number of years.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the years don't fit in the months of the interval.
+    pub const fn years(years: i32) -> Interval {
+        match years.checked_mul(MONTHS_PER_YEAR) {
+            Some(months) => Interval::new(months, 0, 0),
+            None => panic!("interval out of range"),
+        }
+    }
+
+    /// Create an interval of the number of months.
+    pub const fn months(months: i32) -> Interval {
+        Interval::new(months, 0, 0)
+    }
+
+    /// Create an interval of the number of weeks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the weeks don't fit in the days of the interval.
+    pub const fn weeks(weeks: i32) -> Interval {
+        match weeks.checked_mul(DAYS_PER_WEEK) {
+            Some(days) => Interval::new(0, days, 0),
+            None => panic!("interval out of range"),
+        }
+    }
+
+    /// Create an interval of the number of days.
+    pub const fn days(days: i32) -> Interval {
+        Interval::new(0, days, 0)
+    }
+
+    /// Create an interval of the number of hours.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hours don't fit in the microseconds of the interval.
+    pub const fn hours(hours: i64) -> Interval {
+        Interval::time(hours, MICROS_PER_HOUR)
+    }
+
+    /// Create an interval of the number of minutes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the minutes don't fit in the microseconds of the interval.
+    pub const fn minutes(minutes: i64) -> Interval {
+        Interval::time(minutes, MICROS_PER_MINUTE)
+    }
+
+    /// Create an interval of the number of seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the seconds don't fit in the microseconds of the interval.
+    pub const fn seconds(seconds: i64) -> Interval {
+        Interval::time(seconds, MICROS_PER_SECOND)
+    }
+
+    /// Create an interval of the number of milliseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the milliseconds don't fit in the microseconds of the interval.
+    pub const fn millis(millis: i64) -> Interval {
+        Interval::time(millis, MICROS_PER_MILLI)
+    }
+
+    /// Create an interval of the number of microseconds.
+    pub const fn micros(micros: i64) -> Interval {
+        Interval::new(0, 0, micros)
+    }
+
+    const fn time(value: i64, scale: i64) -> Interval {
+        match value.checked_mul(scale) {
+            Some(micros) => Interval::new(0, 0, micros),
+            None => panic!("interval out of range"),
+        }
+    }
+
+    /// Is the interval neither `infinity` nor `-infinity`?
+    pub fn is_finite(&self) -> bool {
+        !self.is_infinite()
+    }
+
+    /// Is the interval either `infinity` or `-infinity`?
+    pub fn is_infinite(&self) -> bool {
+        *self == Interval::INFINITY || *self == Interval::NEG_INFINITY
+    }
+
     /// Output the interval as iso 8601 compliant string.
     pub fn to_iso_8601(&self) -> String {
-        IntervalNorm::from(self).into_iso_8601()
+        self.display(IntervalStyle::Iso8601).to_string()
+    }
+
+    /// Output the interval in the iso 8601 alternative format, e.g.
+    /// `P0001-02-03T04:05:06`.
+    pub fn to_iso_8601_alternative(&self) -> String {
+        let mut out = String::new();
+        // writing into a string can't fail.
+        let _ = self.write_norm(&mut out, IntervalNorm::write_iso_8601_alternative);
+        out
     }
 
     /// Output the interval as a postgres interval string.
     pub fn to_postgres(&self) -> String {
-        IntervalNorm::from(self).into_postgres()
+        self.display(IntervalStyle::Postgres).to_string()
     }
 
     /// Output the interval as a postgres_verbose interval string.
     pub fn to_postgres_verbose(&self) -> String {
-        IntervalNorm::from(self).into_postgres_verbose()
+        self.display(IntervalStyle::PostgresVerbose).to_string()
     }
 
     ///Output the interval as a sql compliant interval string.
     pub fn to_sql(&self) -> String {
-        IntervalNorm::from(self).into_sql()
+        self.display(IntervalStyle::SqlStandard).to_string()
     }
 }
 
@@ -72,6 +218,68 @@ mod tests {
         assert!(interval != different_interval);
     }
 
+    #[test]
+    fn test_unit_constructors() {
+        const HOUR: Interval = Interval::hours(1);
+        assert_eq!(HOUR, Interval::new(0, 0, 3_600_000_000));
+        assert_eq!(Interval::years(2), Interval::new(24, 0, 0));
+        assert_eq!(Interval::months(-3), Interval::new(-3, 0, 0));
+        assert_eq!(Interval::weeks(2), Interval::new(0, 14, 0));
+        assert_eq!(Interval::days(5), Interval::new(0, 5, 0));
+        assert_eq!(Interval::minutes(-2), Interval::new(0, 0, -120_000_000));
+        assert_eq!(Interval::seconds(3), Interval::new(0, 0, 3_000_000));
+        assert_eq!(Interval::millis(4), Interval::new(0, 0, 4_000));
+        assert_eq!(Interval::micros(5), Interval::new(0, 0, 5));
+    }
+
+    #[test]
+    #[should_panic(expected = "interval out of range")]
+    fn test_years_overflow_panics() {
+        let _ = Interval::years(i32::MAX / 12 + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "interval out of range")]
+    fn test_hours_overflow_panics() {
+        let _ = Interval::hours(i64::MAX / 3_600_000_000 + 1);
+    }
+
+    #[test]
+    fn test_min_max() {
+        assert!(Interval::MAX.is_finite());
+        assert!(Interval::MIN.is_finite());
+        assert!(Interval::MAX.pg_cmp(&Interval::INFINITY).is_lt());
+        assert!(Interval::MIN.pg_cmp(&Interval::NEG_INFINITY).is_gt());
+        assert_eq!(Interval::ZERO, Interval::new(0, 0, 0));
+    }
+
+    #[test]
+    fn test_is_finite() {
+        assert!(Interval::new(i32::MAX, i32::MAX, 0).is_finite());
+        assert!(Interval::new(i32::MIN, i32::MIN, i64::MAX).is_finite());
+        assert!(!Interval::INFINITY.is_finite());
+        assert!(!Interval::NEG_INFINITY.is_finite());
+        assert!(Interval::INFINITY.is_infinite());
+    }
+
+    #[test]
+    fn test_infinity_output() {
+        let interval = Interval::INFINITY;
+        assert_eq!(interval.to_iso_8601(), "infinity");
+        assert_eq!(interval.to_postgres(), "infinity");
+        assert_eq!(interval.to_postgres_verbose(), "infinity");
+        assert_eq!(interval.to_sql(), "infinity");
+    }
+
+    #[test]
+    fn test_neg_infinity_output() {
+        let interval = Interval::NEG_INFINITY;
+        assert_eq!(interval.to_iso_8601(), "-infinity");
+        assert_eq!(interval.to_postgres(), "-infinity");
+        assert_eq!(interval.to_postgres_verbose(), "-infinity");
+        assert_eq!(interval.to_sql(), "-infinity");
+    }
+
     #[test]
     fn test_iso_1() {
         let interval = Interval::new(12, 0, 0);
@@ -223,7 +431,84 @@ mod tests {
     fn test_8601_22() {
         let interval = Interval::new(0, 0, 500000);
         let output = interval.to_iso_8601();
-        assert_eq!(String::from("PT.500000S"), output);
+        assert_eq!(String::from("PT0.500000S"), output);
+    }
+
+    #[test]
+    fn test_8601_23() {
+        let interval = Interval::new(0, 0, -500000);
+        let output = interval.to_iso_8601();
+        assert_eq!(String::from("PT-0.500000S"), output);
+    }
+
+    #[test]
+    fn test_8601_alternative_1() {
+        let interval = Interval::new(14, 3, 14_706_000_000);
+        let output = interval.to_iso_8601_alternative();
+        assert_eq!(String::from("P0001-02-03T04:05:06"), output);
+    }
+
+    #[test]
+    fn test_8601_alternative_2() {
+        let interval = Interval::new(0, 0, 0);
+        let output = interval.to_iso_8601_alternative();
+        assert_eq!(String::from("P0000-00-00T00:00:00"), output);
+    }
+
+    #[test]
+    fn test_8601_alternative_3() {
+        let interval = Interval::new(-14, -3, -14_706_500_000);
+        let output = interval.to_iso_8601_alternative();
+        assert_eq!(String::from("P-0001--02--03T-04:-05:-06.500000"), output);
+    }
+
+    #[test]
+    fn test_8601_alternative_4() {
+        let interval = Interval::new(1, -1, -500_000);
+        let output = interval.to_iso_8601_alternative();
+        assert_eq!(String::from("P0000-01--01T00:00:-00.500000"), output);
+    }
+
+    #[test]
+    fn test_8601_alternative_5() {
+        assert_eq!(Interval::INFINITY.to_iso_8601_alternative(), "infinity");
+        assert_eq!(
+            Interval::NEG_INFINITY.to_iso_8601_alternative(),
+            "-infinity"
+        );
+    }
+
+    #[test]
+    fn test_8601_alternative_round_trip() {
+        for interval in [
+            Interval::new(14, 3, 14_706_000_000),
+            Interval::new(-14, -3, -14_706_500_000),
+            Interval::new(1, -1, 1),
+            Interval::new(-1, 1, -1),
+            Interval::new(0, 0, -500_000),
+            Interval::new(11, 0, 3_599_999_999),
+            Interval::new(i32::MAX, i32::MIN, 0),
+            Interval::MAX,
+            Interval::MIN,
+            Interval::INFINITY,
+            Interval::NEG_INFINITY,
+        ] {
+            let alternative = interval.to_iso_8601_alternative();
+            let designators = interval.to_iso_8601();
+            assert_eq!(
+                Interval::from_iso(&alternative),
+                Ok(interval),
+                "{}",
+                alternative
+            );
+            assert_eq!(
+                Interval::from_iso(&alternative),
+                Interval::from_iso(&designators),
+                "{} {}",
+                alternative,
+                designators
+            );
+        }
     }
 
     #[test]
@@ -513,6 +798,20 @@ mod tests {
         assert_eq!(String::from("-1:10:15"), output);
     }
 
+    #[test]
+    fn test_sql_19() {
+        let interval = Interval::new(-1, 0, 0);
+        let output = interval.to_sql();
+        assert_eq!(String::from("-0-1"), output);
+    }
+
+    #[test]
+    fn test_sql_20() {
+        let interval = Interval::new(0, i32::MIN, 1);
+        let output = interval.to_sql();
+        assert_eq!(String::from("+0-0 -2147483648 +0:00:00.000001"), output);
+    }
+
     #[test]
     fn test_postgres_verbose_zero() {
         let interval = Interval::new(0, 0, 0);
@@ -637,4 +936,11 @@ mod tests {
         let output = interval.to_postgres_verbose();
         assert_eq!(String::from("@ 5.678901 secs"), output);
     }
+
+    #[test]
+    fn test_postgres_verbose_min_days() {
+        let interval = Interval::new(0, i32::MIN, 0);
+        let output = interval.to_postgres_verbose();
+        assert_eq!(String::from("@ 2147483648 days ago"), output);
+    }
 }
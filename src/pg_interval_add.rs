@@ -3,13 +3,25 @@ use std::ops;
 
 impl Interval {
     /// Checked interval addition. Computes `Interval + Interval` and `None` if there
-    /// was an overflow.
+    /// was an overflow. Like postgres adding an infinite interval gives that infinity,
+    /// adding `infinity` and `-infinity` is `None`, and a finite sum that would land on
+    /// one of the infinities is treated as an overflow.
     pub fn checked_add(self, other_interval: Interval) -> Option<Interval> {
-        Some(Interval {
+        if self.is_infinite() {
+            if other_interval.is_infinite() && other_interval != self {
+                return None;
+            }
+            return Some(self);
+        }
+        if other_interval.is_infinite() {
+            return Some(other_interval);
+        }
+        let interval = Interval {
             months: self.months.checked_add(other_interval.months)?,
             days: self.days.checked_add(other_interval.days)?,
             microseconds: self.microseconds.checked_add(other_interval.microseconds)?,
-        })
+        };
+        interval.is_finite().then_some(interval)
     }
 
     /// Shortcut method to add day time part to the interval. Any units smaller than a microsecond
@@ -74,11 +86,8 @@ impl Interval {
 impl ops::Add for Interval {
     type Output = Interval;
     fn add(self, other_interval: Interval) -> Interval {
-        Interval {
-            months: self.months + other_interval.months,
-            days: self.days + other_interval.days,
-            microseconds: self.microseconds + other_interval.microseconds,
-        }
+        self.checked_add(other_interval)
+            .expect("interval out of range")
     }
 }
 
@@ -101,6 +110,37 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    #[test]
+    fn test_checked_add_infinity() {
+        let interval = Interval::new(1, 2, 3);
+        assert_eq!(
+            interval.checked_add(Interval::INFINITY),
+            Some(Interval::INFINITY)
+        );
+        assert_eq!(
+            Interval::NEG_INFINITY.checked_add(interval),
+            Some(Interval::NEG_INFINITY)
+        );
+        assert_eq!(
+            Interval::INFINITY.checked_add(Interval::INFINITY),
+            Some(Interval::INFINITY)
+        );
+        assert_eq!(Interval::INFINITY.checked_add(Interval::NEG_INFINITY), None);
+        assert_eq!(Interval::NEG_INFINITY.checked_add(Interval::INFINITY), None);
+    }
+
+    #[test]
+    fn test_checked_add_reaching_infinity() {
+        let interval = Interval::new(i32::MAX, i32::MAX, i64::MAX - 1);
+        assert_eq!(interval.checked_add(Interval::new(0, 0, 1)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "interval out of range")]
+    fn test_add_overflow_panics() {
+        let _ = Interval::new(i32::MAX, 0, 0) + Interval::new(1, 0, 0);
+    }
+
     #[test]
     fn test_checked_add_day_time() {
         let interval = Interval::new(13, 0, 0);
@@ -0,0 +1,202 @@
+//! Postgres' `age` and timestamp subtraction. These work on the calendar fields of
+//! any `IntervalTimestamp` so the chrono, time and jiff integrations share them,
+//! rather than each keeping a copy next to its duration conversions.
+use crate::integrations::conversion_error::ConversionError;
+use crate::pg_interval::{MICROS_PER_DAY, MICROS_PER_SECOND};
+use crate::{Interval, interval_norm::IntervalNorm};
+
+// the days from 0000-03-01 to 1970-01-01 and the days in 400 years.
+const DAYS_TO_UNIX_EPOCH: i64 = 719_468;
+const DAYS_PER_ERA: i64 = 146_097;
+
+/// Timestamps, or dates, postgres' `age` and timestamp subtraction can be computed
+/// for. Dates are treated as midnight of that day.
+pub trait IntervalTimestamp: Copy {
+    /// The microseconds since `1970-01-01 00:00:00`, truncating any smaller units.
+    /// Will return `None` if they don't fit in an `i64`.
+    fn unix_micros(self) -> Option<i64>;
+}
+
+/// The calendar fields of a timestamp in the proleptic gregorian calendar.
+struct Timestamp {
+    year: i64,
+    month: i64,
+    day: i64,
+    hour: i64,
+    minute: i64,
+    second: i64,
+    microsecond: i64,
+}
+
+impl Timestamp {
+    fn from_unix_micros(micros: i64) -> Timestamp {
+        let days = micros.div_euclid(MICROS_PER_DAY);
+        let time = micros.rem_euclid(MICROS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        let secs = time / MICROS_PER_SECOND;
+        Timestamp {
+            year,
+            month,
+            day,
+            hour: secs / 3600,
+            minute: secs % 3600 / 60,
+            second: secs % 60,
+            microsecond: time % MICROS_PER_SECOND,
+        }
+    }
+}
+
+/// Converts the days since `1970-01-01` into the year, month and day, see
+/// <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + DAYS_TO_UNIX_EPOCH;
+    let era = days.div_euclid(DAYS_PER_ERA);
+    let day_of_era = days - era * DAYS_PER_ERA;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // the months start at march so the leap day is at the end of the year.
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+pub(crate) fn days_in_month(year: i64, month: i64) -> i64 {
+    let is_leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    match month {
+        2 if is_leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn out_of_range() -> ConversionError {
+    ConversionError::from_out_of_range("Interval out of range.")
+}
+
+impl Interval {
+    /// Computes the symbolic difference between two timestamps, or dates, the same
+    /// way postgres' `age` does. The result is in years, months and days, borrowing
+    /// from the actual length of the months. Any units smaller than a microsecond
+    /// will be truncated.
+    pub fn age<T: IntervalTimestamp>(later: T, earlier: T) -> Result<Interval, ConversionError> {
+        let later = later.unix_micros().ok_or_else(out_of_range)?;
+        let earlier = earlier.unix_micros().ok_or_else(out_of_range)?;
+        let is_negative = later < earlier;
+        let (max, min) = if is_negative {
+            (earlier, later)
+        } else {
+            (later, earlier)
+        };
+        let max = Timestamp::from_unix_micros(max);
+        let min = Timestamp::from_unix_micros(min);
+        let mut years = max.year - min.year;
+        let mut months = max.month - min.month;
+        let mut days = max.day - min.day;
+        let mut hours = max.hour - min.hour;
+        let mut minutes = max.minute - min.minute;
+        let mut seconds = max.second - min.second;
+        let mut microseconds = max.microsecond - min.microsecond;
+        // propagate any negative fields into the next larger unit.
+        while microseconds < 0 {
+            microseconds += MICROS_PER_SECOND;
+            seconds -= 1;
+        }
+        while seconds < 0 {
+            seconds += 60;
+            minutes -= 1;
+        }
+        while minutes < 0 {
+            minutes += 60;
+            hours -= 1;
+        }
+        while hours < 0 {
+            hours += 24;
+            days -= 1;
+        }
+        // borrow the days from the month of the earliest timestamp
+        while days < 0 {
+            days += days_in_month(min.year, min.month);
+            months -= 1;
+        }
+        while months < 0 {
+            months += 12;
+            years -= 1;
+        }
+        let sign = if is_negative { -1 } else { 1 };
+        // an i64 of microseconds spans less than 300,000 years so these can't overflow.
+        let norm_interval = IntervalNorm {
+            years: (sign * years) as i32,
+            months: (sign * months) as i32,
+            days: (sign * days) as i32,
+            hours: sign * hours,
+            minutes: sign * minutes,
+            seconds: sign * seconds,
+            microseconds: sign * microseconds,
+        };
+        norm_interval
+            .try_into_interval()
+            .map_err(|_| out_of_range())
+    }
+
+    /// Computes the difference between two timestamps, or dates, the same way
+    /// postgres' `timestamp - timestamp` does. The result only has days and time,
+    /// with the days being 24 hour periods. Any units smaller than a microsecond
+    /// will be truncated.
+    pub fn between<T: IntervalTimestamp>(
+        later: T,
+        earlier: T,
+    ) -> Result<Interval, ConversionError> {
+        let later = later.unix_micros().ok_or_else(out_of_range)?;
+        let earlier = earlier.unix_micros().ok_or_else(out_of_range)?;
+        later
+            .checked_sub(earlier)
+            .and_then(|microseconds| Interval::new(0, 0, microseconds).checked_justify_hours())
+            .ok_or_else(out_of_range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(civil_from_days(-719_468), (0, 3, 1));
+        assert_eq!(civil_from_days(-719_469), (0, 2, 29));
+    }
+
+    #[test]
+    fn test_timestamp_before_epoch() {
+        // 1969-12-31 23:59:59.999999
+        let timestamp = Timestamp::from_unix_micros(-1);
+        assert_eq!(
+            (timestamp.year, timestamp.month, timestamp.day),
+            (1969, 12, 31)
+        );
+        assert_eq!(
+            (timestamp.hour, timestamp.minute, timestamp.second),
+            (23, 59, 59)
+        );
+        assert_eq!(timestamp.microsecond, 999_999);
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(2023, 4), 30);
+        assert_eq!(days_in_month(2023, 12), 31);
+    }
+}
@@ -0,0 +1,202 @@
+use crate::Interval;
+use crate::pg_interval::{DAYS_PER_MONTH, MICROS_PER_DAY};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+impl Interval {
+    /// The value postgres uses to compare intervals. The months are treated as 30 days
+    /// and the days are treated as 24 hours, giving the total number of microseconds.
+    pub fn cmp_value(&self) -> i128 {
+        let days = self.months as i64 * DAYS_PER_MONTH as i64 + self.days as i64;
+        days as i128 * MICROS_PER_DAY as i128 + self.microseconds as i128
+    }
+
+    /// Compares two intervals the same way postgres does, so `1 mon` is equal
+    /// to `30 days` and `1 day` is equal to `24 hours`.
+    pub fn pg_cmp(&self, other: &Interval) -> Ordering {
+        self.cmp_value().cmp(&other.cmp_value())
+    }
+
+    /// Are the two intervals equal according to postgres?
+    pub fn pg_eq(&self, other: &Interval) -> bool {
+        self.cmp_value() == other.cmp_value()
+    }
+}
+
+/// Wrapper around `Interval` that uses postgres' semantics for equality,
+/// ordering and hashing rather than comparing field by field. Useful for
+/// sorting, de-duplicating or keying maps by intervals so the results match
+/// `ORDER BY` and `DISTINCT` on the server.
+#[derive(Clone, Copy, Debug)]
+pub struct PgOrd(pub Interval);
+
+impl PgOrd {
+    /// Unwraps the interval.
+    pub fn into_inner(self) -> Interval {
+        self.0
+    }
+}
+
+impl From<Interval> for PgOrd {
+    fn from(interval: Interval) -> PgOrd {
+        PgOrd(interval)
+    }
+}
+
+impl From<PgOrd> for Interval {
+    fn from(ord: PgOrd) -> Interval {
+        ord.0
+    }
+}
+
+impl PartialEq for PgOrd {
+    fn eq(&self, other: &PgOrd) -> bool {
+        self.0.pg_eq(&other.0)
+    }
+}
+
+impl Eq for PgOrd {}
+
+impl PartialOrd for PgOrd {
+    fn partial_cmp(&self, other: &PgOrd) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PgOrd {
+    fn cmp(&self, other: &PgOrd) -> Ordering {
+        self.0.pg_cmp(&other.0)
+    }
+}
+
+impl Hash for PgOrd {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.cmp_value().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_cmp_value() {
+        let interval = Interval::new(1, 1, 1);
+        assert_eq!(interval.cmp_value(), 31 * MICROS_PER_DAY as i128 + 1);
+    }
+
+    #[test]
+    fn test_cmp_value_extremes() {
+        let interval = Interval::new(i32::MAX, i32::MAX, i64::MAX);
+        let expected = (i32::MAX as i128 * 31) * MICROS_PER_DAY as i128 + i64::MAX as i128;
+        assert_eq!(interval.cmp_value(), expected);
+        let interval = Interval::new(i32::MIN, i32::MIN, i64::MIN);
+        let expected = (i32::MIN as i128 * 31) * MICROS_PER_DAY as i128 + i64::MIN as i128;
+        assert_eq!(interval.cmp_value(), expected);
+    }
+
+    #[test]
+    fn test_infinity_sorts_last() {
+        let largest = Interval::new(i32::MAX, i32::MAX, i64::MAX - 1);
+        let smallest = Interval::new(i32::MIN, i32::MIN, i64::MIN + 1);
+        assert_eq!(Interval::INFINITY.pg_cmp(&largest), Ordering::Greater);
+        assert_eq!(Interval::NEG_INFINITY.pg_cmp(&smallest), Ordering::Less);
+        assert!(Interval::INFINITY.pg_eq(&Interval::INFINITY));
+    }
+
+    #[test]
+    fn test_pg_eq_month_is_30_days() {
+        let month = Interval::new(1, 0, 0);
+        let days = Interval::new(0, 30, 0);
+        assert!(month.pg_eq(&days));
+        assert_ne!(month, days);
+        assert_eq!(PgOrd(month), PgOrd(days));
+    }
+
+    #[test]
+    fn test_pg_eq_day_is_24_hours() {
+        let day = Interval::new(0, 1, 0);
+        let hours = Interval::new(0, 0, MICROS_PER_DAY);
+        assert_eq!(PgOrd(day), PgOrd(hours));
+    }
+
+    #[test]
+    fn test_pg_cmp_mixed_sign() {
+        // '1 mon -1 hour' < '30 days'
+        let lhs = Interval::new(1, 0, -3_600_000_000);
+        let rhs = Interval::new(0, 30, 0);
+        assert_eq!(lhs.pg_cmp(&rhs), Ordering::Less);
+        assert!(PgOrd(lhs) < PgOrd(rhs));
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut intervals = vec![
+            PgOrd(Interval::new(1, 0, 0)),
+            PgOrd(Interval::new(0, 29, MICROS_PER_DAY + 1)),
+            PgOrd(Interval::new(0, 0, -1)),
+            PgOrd(Interval::new(0, 29, 0)),
+        ];
+        intervals.sort();
+        let sorted: Vec<Interval> = intervals.into_iter().map(PgOrd::into_inner).collect();
+        assert_eq!(
+            sorted,
+            vec![
+                Interval::new(0, 0, -1),
+                Interval::new(0, 29, 0),
+                Interval::new(1, 0, 0),
+                Interval::new(0, 29, MICROS_PER_DAY + 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hash_agrees_with_eq() {
+        let mut set = HashSet::new();
+        set.insert(PgOrd(Interval::new(1, 0, 0)));
+        set.insert(PgOrd(Interval::new(0, 30, 0)));
+        set.insert(PgOrd(Interval::new(0, 29, MICROS_PER_DAY)));
+        set.insert(PgOrd(Interval::new(0, 0, 1)));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let month = PgOrd(Interval::new(1, 0, 0));
+        let days = PgOrd(Interval::new(0, 31, 0));
+        assert_eq!(month.min(days).into_inner(), Interval::new(1, 0, 0));
+        assert_eq!(month.max(days).into_inner(), Interval::new(0, 31, 0));
+    }
+
+    #[test]
+    fn test_min_max_equal_keeps_order() {
+        // when the values compare equal min returns the first argument
+        // and max returns the second.
+        let month = PgOrd(Interval::new(1, 0, 0));
+        let days = PgOrd(Interval::new(0, 30, 0));
+        assert_eq!(month.min(days).into_inner(), Interval::new(1, 0, 0));
+        assert_eq!(month.max(days).into_inner(), Interval::new(0, 30, 0));
+    }
+
+    #[test]
+    fn test_clamp() {
+        let lower = PgOrd(Interval::new(0, 1, 0));
+        let upper = PgOrd(Interval::new(1, 0, 0));
+        let below = PgOrd(Interval::new(0, 0, 3_600_000_000));
+        let above = PgOrd(Interval::new(0, 31, 0));
+        let inside = PgOrd(Interval::new(0, 2, 0));
+        assert_eq!(
+            below.clamp(lower, upper).into_inner(),
+            Interval::new(0, 1, 0)
+        );
+        assert_eq!(
+            above.clamp(lower, upper).into_inner(),
+            Interval::new(1, 0, 0)
+        );
+        assert_eq!(
+            inside.clamp(lower, upper).into_inner(),
+            Interval::new(0, 2, 0)
+        );
+    }
+}
@@ -0,0 +1,506 @@
+use crate::interval_norm::IntervalNorm;
+use crate::pg_interval::{DAYS_PER_MONTH, MICROS_PER_SECOND, MONTHS_PER_YEAR, SECS_PER_DAY};
+use crate::{Interval, ParseError};
+use std::fmt;
+use std::str::FromStr;
+
+/// The fields of an interval postgres can extract or truncate to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IntervalField {
+    Microseconds,
+    Milliseconds,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Quarter,
+    Year,
+    Decade,
+    Century,
+    Millennium,
+    Epoch,
+}
+
+impl FromStr for IntervalField {
+    type Err = ParseError;
+
+    /// Parses the field using the same names and abbreviations postgres accepts,
+    /// ignoring case.
+    fn from_str(field: &str) -> Result<IntervalField, ParseError> {
+        match &*field.trim().to_lowercase() {
+            "microseconds" | "microsecond" | "microsecon" | "usecs" | "usec" | "us" => {
+                Ok(IntervalField::Microseconds)
+            }
+            "milliseconds" | "millisecond" | "millisecon" | "msecs" | "msec" | "ms" => {
+                Ok(IntervalField::Milliseconds)
+            }
+            "seconds" | "second" | "secs" | "sec" | "s" => Ok(IntervalField::Second),
+            "minutes" | "minute" | "mins" | "min" | "m" => Ok(IntervalField::Minute),
+            "hours" | "hour" | "hrs" | "hr" | "h" => Ok(IntervalField::Hour),
+            "days" | "day" | "d" => Ok(IntervalField::Day),
+            "months" | "month" | "mons" | "mon" => Ok(IntervalField::Month),
+            "quarter" | "qtr" => Ok(IntervalField::Quarter),
+            "years" | "year" | "yrs" | "yr" | "y" => Ok(IntervalField::Year),
+            "decades" | "decade" | "decs" | "dec" => Ok(IntervalField::Decade),
+            "centuries" | "century" | "cent" | "c" => Ok(IntervalField::Century),
+            "millennia" | "millennium" | "millenniums" | "mils" | "mil" => {
+                Ok(IntervalField::Millennium)
+            }
+            "epoch" => Ok(IntervalField::Epoch),
+            _ => Err(ParseError::from_invalid_interval(&format!(
+                "Unit \"{}\" not recognized.",
+                field
+            ))),
+        }
+    }
+}
+
+/// An exact decimal value, stored as an integer and the number of digits after the
+/// decimal point. Mirrors the `numeric` values postgres returns from `extract`,
+/// including the infinities returned for infinite intervals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalNumeric {
+    value: i128,
+    scale: u32,
+    infinite: bool,
+}
+
+impl IntervalNumeric {
+    /// The numeric postgres outputs as `Infinity`.
+    pub const INFINITY: IntervalNumeric = IntervalNumeric {
+        value: 1,
+        scale: 0,
+        infinite: true,
+    };
+
+    /// The numeric postgres outputs as `-Infinity`.
+    pub const NEG_INFINITY: IntervalNumeric = IntervalNumeric {
+        value: -1,
+        scale: 0,
+        infinite: true,
+    };
+
+    /// Create a new numeric representing `value / 10^scale`.
+    pub fn new(value: i128, scale: u32) -> IntervalNumeric {
+        IntervalNumeric {
+            value,
+            scale,
+            infinite: false,
+        }
+    }
+
+    /// The unscaled integer value. For the infinities this is the sign, `1` or `-1`.
+    pub fn value(&self) -> i128 {
+        self.value
+    }
+
+    /// The number of digits after the decimal point.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Is the numeric neither `Infinity` nor `-Infinity`?
+    pub fn is_finite(&self) -> bool {
+        !self.infinite
+    }
+
+    /// Converts the numeric into a float, this may lose precision.
+    pub fn to_f64(&self) -> f64 {
+        if self.infinite {
+            self.value as f64 * f64::INFINITY
+        } else {
+            self.value as f64 / 10_f64.powi(self.scale as i32)
+        }
+    }
+}
+
+impl fmt::Display for IntervalNumeric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.value < 0 { "-" } else { "" };
+        if self.infinite {
+            return write!(f, "{}Infinity", sign);
+        }
+        let divisor = 10_u128.pow(self.scale);
+        let abs_value = self.value.unsigned_abs();
+        if self.scale == 0 {
+            write!(f, "{}{}", sign, abs_value)
+        } else {
+            write!(
+                f,
+                "{}{}.{:0width$}",
+                sign,
+                abs_value / divisor,
+                abs_value % divisor,
+                width = self.scale as usize
+            )
+        }
+    }
+}
+
+impl Interval {
+    /// Extracts the field from the interval the same way postgres' `extract` does.
+    /// The months are broken down into years and months and the time into hours,
+    /// minutes and seconds, each keeping the sign of the interval part they come
+    /// from. The epoch is the total number of seconds treating years as 365.25 days
+    /// and months as 30 days.
+    ///
+    /// Like postgres, infinite intervals give an infinity for the fields that keep
+    /// growing with the interval, such as the epoch or years, and `None` (`NULL`) for
+    /// the fields that wrap around, such as the seconds or months.
+    pub fn extract(&self, field: IntervalField) -> Option<IntervalNumeric> {
+        if self.is_infinite() {
+            return self.extract_infinite(field);
+        }
+        let norm = IntervalNorm::from(self);
+        let years = norm.years as i128;
+        let total_micros = norm.seconds * MICROS_PER_SECOND + norm.microseconds;
+        let numeric = match field {
+            IntervalField::Microseconds => IntervalNumeric::new(total_micros as i128, 0),
+            IntervalField::Milliseconds => IntervalNumeric::new(total_micros as i128, 3),
+            IntervalField::Second => IntervalNumeric::new(total_micros as i128, 6),
+            IntervalField::Minute => IntervalNumeric::new(norm.minutes as i128, 0),
+            IntervalField::Hour => IntervalNumeric::new(norm.hours as i128, 0),
+            IntervalField::Day => IntervalNumeric::new(norm.days as i128, 0),
+            IntervalField::Month => IntervalNumeric::new(norm.months as i128, 0),
+            IntervalField::Quarter => IntervalNumeric::new(self.quarter() as i128, 0),
+            IntervalField::Year => IntervalNumeric::new(years, 0),
+            IntervalField::Decade => IntervalNumeric::new(years / 10, 0),
+            IntervalField::Century => IntervalNumeric::new(years / 100, 0),
+            IntervalField::Millennium => IntervalNumeric::new(years / 1000, 0),
+            IntervalField::Epoch => {
+                // multiply everything by 4 so the 365.25 day years can be
+                // calculated using integers.
+                let secs_from_day_months = (1461 * (self.months / MONTHS_PER_YEAR) as i128
+                    + 4 * DAYS_PER_MONTH as i128 * (self.months % MONTHS_PER_YEAR) as i128
+                    + 4 * self.days as i128)
+                    * (SECS_PER_DAY / 4) as i128;
+                let micros =
+                    secs_from_day_months * MICROS_PER_SECOND as i128 + self.microseconds as i128;
+                IntervalNumeric::new(micros, 6)
+            }
+        };
+        Some(numeric)
+    }
+
+    /// Extracts the field from the interval as a float, the same way postgres'
+    /// `date_part` does. Infinite intervals are handled like `extract`.
+    pub fn extract_f64(&self, field: IntervalField) -> Option<f64> {
+        if self.is_infinite() {
+            return self.extract_infinite(field).map(|numeric| numeric.to_f64());
+        }
+        let norm = IntervalNorm::from(self);
+        let value = match field {
+            IntervalField::Milliseconds => {
+                norm.seconds as f64 * 1000.0 + norm.microseconds as f64 / 1000.0
+            }
+            IntervalField::Second => {
+                norm.seconds as f64 + norm.microseconds as f64 / MICROS_PER_SECOND as f64
+            }
+            IntervalField::Epoch => {
+                let days_per_year = 365.25;
+                self.microseconds as f64 / MICROS_PER_SECOND as f64
+                    + days_per_year * SECS_PER_DAY as f64 * (self.months / MONTHS_PER_YEAR) as f64
+                    + (DAYS_PER_MONTH as i64 * SECS_PER_DAY) as f64
+                        * (self.months % MONTHS_PER_YEAR) as f64
+                    + SECS_PER_DAY as f64 * self.days as f64
+            }
+            _ => self.extract(field)?.to_f64(),
+        };
+        Some(value)
+    }
+
+    /// Mirrors postgres' `NonFiniteIntervalPart`. Every field is listed so a new
+    /// field has to decide whether it grows with the interval.
+    fn extract_infinite(&self, field: IntervalField) -> Option<IntervalNumeric> {
+        match field {
+            IntervalField::Microseconds
+            | IntervalField::Milliseconds
+            | IntervalField::Second
+            | IntervalField::Minute
+            | IntervalField::Month
+            | IntervalField::Quarter => None,
+            IntervalField::Hour
+            | IntervalField::Day
+            | IntervalField::Year
+            | IntervalField::Decade
+            | IntervalField::Century
+            | IntervalField::Millennium
+            | IntervalField::Epoch => {
+                if *self == Interval::NEG_INFINITY {
+                    Some(IntervalNumeric::NEG_INFINITY)
+                } else {
+                    Some(IntervalNumeric::INFINITY)
+                }
+            }
+        }
+    }
+
+    /// The quarter of the year the months fall in. A negative interval gives the
+    /// negative of the quarter of the sign-reversed interval.
+    fn quarter(&self) -> i32 {
+        if self.months >= 0 {
+            (self.months % MONTHS_PER_YEAR) / 3 + 1
+        } else {
+            -(((-(self.months as i64) % MONTHS_PER_YEAR as i64) / 3) as i32 + 1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3_600_000_000;
+
+    #[test]
+    fn test_extract_epoch() {
+        // extract(epoch from interval '5 days 3 hours') = 442800.000000
+        let interval = Interval::new(0, 5, 3 * HOUR);
+        let result = interval.extract(IntervalField::Epoch).unwrap();
+        assert_eq!(result, IntervalNumeric::new(442_800_000_000, 6));
+        assert_eq!(result.to_string(), "442800.000000");
+        assert_eq!(
+            interval.extract_f64(IntervalField::Epoch).unwrap(),
+            442800.0
+        );
+    }
+
+    #[test]
+    fn test_extract_epoch_uses_fractional_years() {
+        // extract(epoch from interval '1 year 1 mon') = 34149600.000000
+        let interval = Interval::new(13, 0, 0);
+        let result = interval.extract(IntervalField::Epoch).unwrap();
+        assert_eq!(result.to_string(), "34149600.000000");
+        assert_eq!(
+            interval.extract_f64(IntervalField::Epoch).unwrap(),
+            34_149_600.0
+        );
+    }
+
+    #[test]
+    fn test_extract_epoch_does_not_overflow() {
+        let interval = Interval::new(i32::MAX, i32::MAX, i64::MAX - 1);
+        let secs =
+            (1461 * (i32::MAX as i128 / 12) + 120 * (i32::MAX as i128 % 12) + 4 * i32::MAX as i128)
+                * 21_600;
+        let expected = secs * 1_000_000 + i64::MAX as i128 - 1;
+        assert_eq!(
+            interval.extract(IntervalField::Epoch).unwrap().value(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_extract_month() {
+        // extract(month from interval '2 years 3 months') = 3
+        let interval = Interval::new(27, 0, 0);
+        assert_eq!(
+            interval.extract(IntervalField::Month).unwrap().to_string(),
+            "3"
+        );
+        // extract(month from interval '2 years 13 months') = 1
+        let interval = Interval::new(37, 0, 0);
+        assert_eq!(
+            interval.extract(IntervalField::Month).unwrap().to_string(),
+            "1"
+        );
+        assert_eq!(
+            interval.extract(IntervalField::Year).unwrap().to_string(),
+            "3"
+        );
+    }
+
+    #[test]
+    fn test_extract_time_fields() {
+        // interval '4 hours 3 minutes 1.5 seconds'
+        let interval = Interval::new(0, 0, 4 * HOUR + 3 * 60_000_000 + 1_500_000);
+        assert_eq!(
+            interval.extract(IntervalField::Hour).unwrap().to_string(),
+            "4"
+        );
+        assert_eq!(
+            interval.extract(IntervalField::Minute).unwrap().to_string(),
+            "3"
+        );
+        assert_eq!(
+            interval.extract(IntervalField::Second).unwrap().to_string(),
+            "1.500000"
+        );
+        assert_eq!(
+            interval
+                .extract(IntervalField::Milliseconds)
+                .unwrap()
+                .to_string(),
+            "1500.000"
+        );
+        assert_eq!(
+            interval
+                .extract(IntervalField::Microseconds)
+                .unwrap()
+                .to_string(),
+            "1500000"
+        );
+        assert_eq!(interval.extract_f64(IntervalField::Second).unwrap(), 1.5);
+        assert_eq!(
+            interval.extract_f64(IntervalField::Milliseconds).unwrap(),
+            1500.0
+        );
+    }
+
+    #[test]
+    fn test_extract_negative_time_fields() {
+        let interval = Interval::new(0, -40, -(4 * HOUR + 1_500_000));
+        assert_eq!(
+            interval.extract(IntervalField::Hour).unwrap().to_string(),
+            "-4"
+        );
+        assert_eq!(
+            interval.extract(IntervalField::Second).unwrap().to_string(),
+            "-1.500000"
+        );
+        assert_eq!(
+            interval.extract(IntervalField::Day).unwrap().to_string(),
+            "-40"
+        );
+        assert_eq!(interval.extract_f64(IntervalField::Second).unwrap(), -1.5);
+    }
+
+    #[test]
+    fn test_extract_quarter() {
+        assert_eq!(
+            Interval::new(0, 0, 0)
+                .extract(IntervalField::Quarter)
+                .unwrap()
+                .value(),
+            1
+        );
+        assert_eq!(
+            Interval::new(5, 0, 0)
+                .extract(IntervalField::Quarter)
+                .unwrap()
+                .value(),
+            2
+        );
+        assert_eq!(
+            Interval::new(14, 0, 0)
+                .extract(IntervalField::Quarter)
+                .unwrap()
+                .value(),
+            1
+        );
+        assert_eq!(
+            Interval::new(-1, 0, 0)
+                .extract(IntervalField::Quarter)
+                .unwrap()
+                .value(),
+            -1
+        );
+        assert_eq!(
+            Interval::new(-5, 0, 0)
+                .extract(IntervalField::Quarter)
+                .unwrap()
+                .value(),
+            -2
+        );
+        assert_eq!(
+            Interval::new(i32::MIN, 0, 0)
+                .extract(IntervalField::Quarter)
+                .unwrap()
+                .value(),
+            -3
+        );
+    }
+
+    #[test]
+    fn test_extract_decade_century_millennium() {
+        let interval = Interval::new(2015 * 12, 0, 0);
+        assert_eq!(
+            interval.extract(IntervalField::Decade).unwrap().value(),
+            201
+        );
+        assert_eq!(
+            interval.extract(IntervalField::Century).unwrap().value(),
+            20
+        );
+        assert_eq!(
+            interval.extract(IntervalField::Millennium).unwrap().value(),
+            2
+        );
+        // the division truncates towards zero for negative intervals.
+        let interval = Interval::new(-2015 * 12, 0, 0);
+        assert_eq!(
+            interval.extract(IntervalField::Decade).unwrap().value(),
+            -201
+        );
+        assert_eq!(
+            interval.extract(IntervalField::Century).unwrap().value(),
+            -20
+        );
+        assert_eq!(
+            interval.extract(IntervalField::Millennium).unwrap().value(),
+            -2
+        );
+    }
+
+    #[test]
+    fn test_extract_infinity() {
+        // extract(epoch from interval 'infinity') = Infinity
+        let interval = Interval::INFINITY;
+        assert_eq!(
+            interval.extract(IntervalField::Epoch),
+            Some(IntervalNumeric::INFINITY)
+        );
+        assert_eq!(
+            interval.extract(IntervalField::Year).unwrap().to_string(),
+            "Infinity"
+        );
+        assert_eq!(
+            interval.extract(IntervalField::Hour).unwrap().to_f64(),
+            f64::INFINITY
+        );
+        // extract(month from interval 'infinity') is NULL
+        assert_eq!(interval.extract(IntervalField::Month), None);
+        assert_eq!(interval.extract(IntervalField::Second), None);
+        assert_eq!(interval.extract_f64(IntervalField::Second), None);
+    }
+
+    #[test]
+    fn test_extract_neg_infinity() {
+        let interval = Interval::NEG_INFINITY;
+        assert_eq!(
+            interval.extract(IntervalField::Millennium),
+            Some(IntervalNumeric::NEG_INFINITY)
+        );
+        assert_eq!(
+            interval.extract(IntervalField::Day).unwrap().to_string(),
+            "-Infinity"
+        );
+        assert_eq!(
+            interval.extract_f64(IntervalField::Epoch),
+            Some(f64::NEG_INFINITY)
+        );
+        assert_eq!(interval.extract(IntervalField::Quarter), None);
+        assert!(!IntervalNumeric::NEG_INFINITY.is_finite());
+    }
+
+    #[test]
+    fn test_numeric_display() {
+        assert_eq!(IntervalNumeric::new(-5, 6).to_string(), "-0.000005");
+        assert_eq!(
+            IntervalNumeric::new(i128::MIN, 0).to_string(),
+            i128::MIN.to_string()
+        );
+    }
+
+    #[test]
+    fn test_field_from_str() {
+        assert_eq!("EPOCH".parse(), Ok(IntervalField::Epoch));
+        assert_eq!("mons".parse(), Ok(IntervalField::Month));
+        assert_eq!("millennium".parse(), Ok(IntervalField::Millennium));
+        assert_eq!(" us ".parse(), Ok(IntervalField::Microseconds));
+        assert!("fortnight".parse::<IntervalField>().is_err());
+        // postgres has no week field for intervals.
+        assert!("week".parse::<IntervalField>().is_err());
+    }
+}
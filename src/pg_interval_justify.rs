@@ -0,0 +1,296 @@
+use crate::Interval;
+use crate::pg_interval::{DAYS_PER_MONTH, MICROS_PER_DAY};
+
+impl Interval {
+    /// Adjusts the interval so 24 hour time periods are represented as days, mirroring
+    /// postgres' `justify_hours`. The days and time parts are given the same sign.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting days overflow, use `checked_justify_hours` instead to
+    /// handle this case.
+    pub fn justify_hours(self) -> Interval {
+        self.checked_justify_hours().expect("interval out of range")
+    }
+
+    /// Checked version of `justify_hours`. Will return `None` if the days overflow.
+    /// Infinite intervals are returned unchanged.
+    pub fn checked_justify_hours(self) -> Option<Interval> {
+        if self.is_infinite() {
+            return Some(self);
+        }
+        let whole_days = self.microseconds / MICROS_PER_DAY;
+        let mut microseconds = self.microseconds - whole_days * MICROS_PER_DAY;
+        let mut days = self.days.checked_add(whole_days as i32)?;
+        if days > 0 && microseconds < 0 {
+            microseconds += MICROS_PER_DAY;
+            days -= 1;
+        } else if days < 0 && microseconds > 0 {
+            microseconds -= MICROS_PER_DAY;
+            days += 1;
+        }
+        Some(Interval {
+            months: self.months,
+            days,
+            microseconds,
+        })
+    }
+
+    /// Adjusts the interval so 30 day time periods are represented as months, mirroring
+    /// postgres' `justify_days`. The months and days parts are given the same sign.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting months overflow, use `checked_justify_days` instead to
+    /// handle this case.
+    pub fn justify_days(self) -> Interval {
+        self.checked_justify_days().expect("interval out of range")
+    }
+
+    /// Checked version of `justify_days`. Will return `None` if the months overflow.
+    /// Infinite intervals are returned unchanged.
+    pub fn checked_justify_days(self) -> Option<Interval> {
+        if self.is_infinite() {
+            return Some(self);
+        }
+        let whole_months = self.days / DAYS_PER_MONTH;
+        let mut days = self.days - whole_months * DAYS_PER_MONTH;
+        let mut months = self.months.checked_add(whole_months)?;
+        if months > 0 && days < 0 {
+            days += DAYS_PER_MONTH;
+            months -= 1;
+        } else if months < 0 && days > 0 {
+            days -= DAYS_PER_MONTH;
+            months += 1;
+        }
+        Some(Interval {
+            months,
+            days,
+            microseconds: self.microseconds,
+        })
+    }
+
+    /// Adjusts the interval using both `justify_hours` and `justify_days` with the
+    /// additional sign adjustments postgres' `justify_interval` performs, so every
+    /// part of the interval ends up with the same sign.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting months overflow, use `checked_justify_interval` instead
+    /// to handle this case.
+    pub fn justify_interval(self) -> Interval {
+        self.checked_justify_interval()
+            .expect("interval out of range")
+    }
+
+    /// Checked version of `justify_interval`. Will return `None` if the months overflow.
+    /// Infinite intervals are returned unchanged.
+    pub fn checked_justify_interval(self) -> Option<Interval> {
+        if self.is_infinite() {
+            return Some(self);
+        }
+        let mut months = self.months;
+        let mut days = self.days;
+        let mut microseconds = self.microseconds;
+        // pre-justify the days when the days and time share a sign, otherwise
+        // adding the whole days from the time part could overflow.
+        if (days > 0 && microseconds > 0) || (days < 0 && microseconds < 0) {
+            let whole_months = days / DAYS_PER_MONTH;
+            days -= whole_months * DAYS_PER_MONTH;
+            months = months.checked_add(whole_months)?;
+        }
+        // after the pre-justify step the days are either small or have the
+        // opposite sign of the time part so this can't overflow.
+        let whole_days = microseconds / MICROS_PER_DAY;
+        microseconds -= whole_days * MICROS_PER_DAY;
+        days += whole_days as i32;
+
+        let whole_months = days / DAYS_PER_MONTH;
+        days -= whole_months * DAYS_PER_MONTH;
+        months = months.checked_add(whole_months)?;
+
+        if months > 0 && (days < 0 || (days == 0 && microseconds < 0)) {
+            days += DAYS_PER_MONTH;
+            months -= 1;
+        } else if months < 0 && (days > 0 || (days == 0 && microseconds > 0)) {
+            days -= DAYS_PER_MONTH;
+            months += 1;
+        }
+
+        if days > 0 && microseconds < 0 {
+            microseconds += MICROS_PER_DAY;
+            days -= 1;
+        } else if days < 0 && microseconds > 0 {
+            microseconds -= MICROS_PER_DAY;
+            days += 1;
+        }
+        Some(Interval {
+            months,
+            days,
+            microseconds,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_justify_hours() {
+        // justify_hours('27 hours') = '1 day 03:00:00'
+        let interval = Interval::new(0, 0, 27 * 3_600_000_000);
+        assert_eq!(
+            interval.justify_hours(),
+            Interval::new(0, 1, 10_800_000_000)
+        );
+    }
+
+    #[test]
+    fn test_justify_hours_keeps_months() {
+        // justify_hours('6 months 3 days 52 hours 3 minutes 2 seconds')
+        //   = '6 mons 5 days 04:03:02'
+        let interval = Interval::new(6, 3, 187_382_000_000);
+        assert_eq!(
+            interval.justify_hours(),
+            Interval::new(6, 5, 14_582_000_000)
+        );
+    }
+
+    #[test]
+    fn test_justify_hours_mixed_sign() {
+        // justify_hours('1 day -1 hour') = '23:00:00'
+        let interval = Interval::new(0, 1, -3_600_000_000);
+        assert_eq!(
+            interval.justify_hours(),
+            Interval::new(0, 0, 82_800_000_000)
+        );
+        // justify_hours('-1 day 1 hour') = '-23:00:00'
+        let interval = Interval::new(0, -1, 3_600_000_000);
+        assert_eq!(
+            interval.justify_hours(),
+            Interval::new(0, 0, -82_800_000_000)
+        );
+    }
+
+    #[test]
+    fn test_checked_justify_hours_overflow() {
+        // justify_hours('2147483647 days 24 hrs') is out of range
+        let interval = Interval::new(0, i32::MAX, MICROS_PER_DAY);
+        assert_eq!(interval.checked_justify_hours(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "interval out of range")]
+    fn test_justify_hours_overflow_panics() {
+        Interval::new(0, i32::MAX, MICROS_PER_DAY).justify_hours();
+    }
+
+    #[test]
+    fn test_justify_days() {
+        // justify_days('35 days') = '1 mon 5 days'
+        let interval = Interval::new(0, 35, 0);
+        assert_eq!(interval.justify_days(), Interval::new(1, 5, 0));
+    }
+
+    #[test]
+    fn test_justify_days_keeps_time() {
+        // justify_days('6 months 36 days 5 hours 4 minutes 3 seconds')
+        //   = '7 mons 6 days 05:04:03'
+        let interval = Interval::new(6, 36, 18_243_000_000);
+        assert_eq!(interval.justify_days(), Interval::new(7, 6, 18_243_000_000));
+    }
+
+    #[test]
+    fn test_justify_days_mixed_sign() {
+        // justify_days('1 mon -1 day') = '29 days'
+        let interval = Interval::new(1, -1, 0);
+        assert_eq!(interval.justify_days(), Interval::new(0, 29, 0));
+        // justify_days('-1 mon 1 day') = '-29 days'
+        let interval = Interval::new(-1, 1, 0);
+        assert_eq!(interval.justify_days(), Interval::new(0, -29, 0));
+    }
+
+    #[test]
+    fn test_checked_justify_days_overflow() {
+        // justify_days('2147483647 months 30 days') is out of range
+        let interval = Interval::new(i32::MAX, 30, 0);
+        assert_eq!(interval.checked_justify_days(), None);
+        let interval = Interval::new(i32::MIN, -30, 0);
+        assert_eq!(interval.checked_justify_days(), None);
+    }
+
+    #[test]
+    fn test_justify_interval() {
+        // justify_interval('1 mon -1 hour') = '29 days 23:00:00'
+        let interval = Interval::new(1, 0, -3_600_000_000);
+        assert_eq!(
+            interval.justify_interval(),
+            Interval::new(0, 29, 82_800_000_000)
+        );
+    }
+
+    #[test]
+    fn test_justify_interval_negative_mixed_sign() {
+        // justify_interval('-1 mon 1 hour') = '-29 days -23:00:00'
+        let interval = Interval::new(-1, 0, 3_600_000_000);
+        assert_eq!(
+            interval.justify_interval(),
+            Interval::new(0, -29, -82_800_000_000)
+        );
+    }
+
+    #[test]
+    fn test_justify_interval_rolls_up() {
+        // justify_interval('1 mon 29 days 24 hours') = '2 mons'
+        let interval = Interval::new(1, 29, MICROS_PER_DAY);
+        assert_eq!(interval.justify_interval(), Interval::new(2, 0, 0));
+    }
+
+    #[test]
+    fn test_justify_interval_pre_justify() {
+        // justify_interval('2147483647 days 24 hrs') = '5965232 years 4 mons 8 days'
+        let interval = Interval::new(0, i32::MAX, MICROS_PER_DAY);
+        assert_eq!(
+            interval.justify_interval(),
+            Interval::new(5965232 * 12 + 4, 8, 0)
+        );
+        // justify_interval('-2147483648 days -24 hrs') = '-5965232 years -4 mons -9 days'
+        let interval = Interval::new(0, i32::MIN, -MICROS_PER_DAY);
+        assert_eq!(
+            interval.justify_interval(),
+            Interval::new(-(5965232 * 12 + 4), -9, 0)
+        );
+    }
+
+    #[test]
+    fn test_justify_interval_near_limits() {
+        // justify_interval('2147483647 months 30 days -24 hrs')
+        //   = '178956970 years 7 mons 29 days'
+        let interval = Interval::new(i32::MAX, 30, -MICROS_PER_DAY);
+        assert_eq!(interval.justify_interval(), Interval::new(i32::MAX, 29, 0));
+        // justify_interval('-2147483648 months -30 days 24 hrs')
+        //   = '-178956970 years -8 mons -29 days'
+        let interval = Interval::new(i32::MIN, -30, MICROS_PER_DAY);
+        assert_eq!(interval.justify_interval(), Interval::new(i32::MIN, -29, 0));
+    }
+
+    #[test]
+    fn test_justify_infinity() {
+        for interval in [Interval::INFINITY, Interval::NEG_INFINITY] {
+            assert_eq!(interval.justify_hours(), interval);
+            assert_eq!(interval.justify_days(), interval);
+            assert_eq!(interval.justify_interval(), interval);
+        }
+    }
+
+    #[test]
+    fn test_checked_justify_interval_overflow() {
+        // justify_interval('2147483647 months 30 days') is out of range
+        let interval = Interval::new(i32::MAX, 30, 0);
+        assert_eq!(interval.checked_justify_interval(), None);
+        // justify_interval('-2147483648 months -30 days') is out of range
+        let interval = Interval::new(i32::MIN, -30, 0);
+        assert_eq!(interval.checked_justify_interval(), None);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::Interval;
+use crate::pg_interval::{
+    DAYS_PER_WEEK, MICROS_PER_HOUR, MICROS_PER_MINUTE, MICROS_PER_SECOND, MONTHS_PER_YEAR,
+};
+
+/// Builds an interval from its parts the same way postgres' `make_interval` does.
+/// Every part defaults to zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MakeInterval {
+    years: i32,
+    months: i32,
+    weeks: i32,
+    days: i32,
+    hours: i32,
+    mins: i32,
+    secs: f64,
+}
+
+impl MakeInterval {
+    pub fn years(mut self, years: i32) -> MakeInterval {
+        self.years = years;
+        self
+    }
+
+    pub fn months(mut self, months: i32) -> MakeInterval {
+        self.months = months;
+        self
+    }
+
+    pub fn weeks(mut self, weeks: i32) -> MakeInterval {
+        self.weeks = weeks;
+        self
+    }
+
+    pub fn days(mut self, days: i32) -> MakeInterval {
+        self.days = days;
+        self
+    }
+
+    pub fn hours(mut self, hours: i32) -> MakeInterval {
+        self.hours = hours;
+        self
+    }
+
+    pub fn mins(mut self, mins: i32) -> MakeInterval {
+        self.mins = mins;
+        self
+    }
+
+    pub fn secs(mut self, secs: f64) -> MakeInterval {
+        self.secs = secs;
+        self
+    }
+
+    /// Builds the interval. The years are added to the months and the weeks to the
+    /// days, while the hours, minutes and seconds make up the time with the seconds
+    /// rounded half to even to the nearest microsecond. Will return `None` if the
+    /// seconds are not finite, any part overflows or the result is infinite.
+    pub fn build(self) -> Option<Interval> {
+        if !self.secs.is_finite() {
+            return None;
+        }
+        let months = self
+            .years
+            .checked_mul(MONTHS_PER_YEAR)?
+            .checked_add(self.months)?;
+        let days = self
+            .weeks
+            .checked_mul(DAYS_PER_WEEK)?
+            .checked_add(self.days)?;
+        // the hours and minutes are 32 bit so these can't overflow.
+        let time = self.hours as i64 * MICROS_PER_HOUR + self.mins as i64 * MICROS_PER_MINUTE;
+        let secs = (self.secs * MICROS_PER_SECOND as f64).round_ties_even();
+        if secs < i64::MIN as f64 || secs >= -(i64::MIN as f64) {
+            return None;
+        }
+        let interval = Interval::new(months, days, time.checked_add(secs as i64)?);
+        interval.is_finite().then_some(interval)
+    }
+}
+
+impl Interval {
+    /// Starts building an interval from its parts like postgres' `make_interval`.
+    pub fn make_interval() -> MakeInterval {
+        MakeInterval::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_make_interval() {
+        // make_interval(1, 2, 3, 4, 5, 6, 7.5) = '1 year 2 mons 25 days 05:06:07.5'
+        let interval = Interval::make_interval()
+            .years(1)
+            .months(2)
+            .weeks(3)
+            .days(4)
+            .hours(5)
+            .mins(6)
+            .secs(7.5)
+            .build();
+        assert_eq!(
+            interval,
+            Some(Interval::new(
+                14,
+                25,
+                5 * MICROS_PER_HOUR + 6 * MICROS_PER_MINUTE + 7_500_000
+            ))
+        );
+        assert_eq!(Interval::make_interval().build(), Some(Interval::ZERO));
+    }
+
+    #[test]
+    fn test_make_interval_rounds_seconds() {
+        // make_interval(secs => 0.0000025) = '00:00:00.000002'
+        let interval = Interval::make_interval().secs(0.0000025).build();
+        assert_eq!(interval, Some(Interval::micros(2)));
+        let interval = Interval::make_interval().secs(-1.0000015).build();
+        assert_eq!(interval, Some(Interval::micros(-1_000_002)));
+    }
+
+    #[test]
+    fn test_make_interval_overflow() {
+        assert_eq!(Interval::make_interval().years(i32::MAX).build(), None);
+        assert_eq!(
+            Interval::make_interval()
+                .years(1)
+                .months(i32::MAX - 11)
+                .build(),
+            None
+        );
+        assert_eq!(Interval::make_interval().weeks(i32::MAX / 6).build(), None);
+        assert_eq!(Interval::make_interval().secs(1e300).build(), None);
+        assert_eq!(Interval::make_interval().secs(f64::NAN).build(), None);
+        assert_eq!(Interval::make_interval().secs(f64::INFINITY).build(), None);
+        assert_eq!(
+            Interval::make_interval()
+                .hours(i32::MAX)
+                .secs(9.2e12)
+                .build(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_make_interval_mixed_signs() {
+        // make_interval(hours => 1, mins => -90) = '-00:30:00'
+        let interval = Interval::make_interval().hours(1).mins(-90).build();
+        assert_eq!(interval, Some(Interval::minutes(-30)));
+    }
+}
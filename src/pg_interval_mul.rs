@@ -0,0 +1,372 @@
+use crate::Interval;
+use crate::pg_interval::{DAYS_PER_MONTH, MICROS_PER_SECOND, SECS_PER_DAY};
+use std::cmp::Ordering;
+use std::ops;
+
+impl Interval {
+    /// Checked interval multiplication. Computes `Interval * f64` the same way postgres
+    /// does, cascading any fractional months down into days and any fractional days down
+    /// into the time part. The time part is rounded to the nearest microsecond. Will
+    /// return `None` if the factor is not a number or the result is out of range.
+    ///
+    /// Multiplying an infinite interval, or multiplying by an infinite factor, gives an
+    /// infinite interval with the sign of the product. Since intervals have no concept
+    /// of not a number, multiplying zero by infinity is `None`.
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Interval> {
+        if factor.is_nan() {
+            return None;
+        }
+        if self.is_infinite() {
+            return match factor.partial_cmp(&0.0)? {
+                Ordering::Less => self.checked_neg(),
+                Ordering::Equal => None,
+                Ordering::Greater => Some(self),
+            };
+        }
+        if factor.is_infinite() {
+            return match self.cmp_value().cmp(&0) {
+                Ordering::Equal => None,
+                sign if (sign == Ordering::Less) == (factor < 0.0) => Some(Interval::INFINITY),
+                _ => Some(Interval::NEG_INFINITY),
+            };
+        }
+        let months = self.months as f64 * factor;
+        let days = self.days as f64 * factor;
+        let time = self.microseconds as f64 * factor;
+        cascade(months, days, time)
+    }
+
+    /// Checked interval multiplication by an integer. Postgres converts the integer to a
+    /// double before multiplying, so this behaves exactly like `checked_mul_f64`.
+    pub fn checked_mul_i32(self, factor: i32) -> Option<Interval> {
+        self.checked_mul_f64(factor as f64)
+    }
+
+    /// Checked interval division. Computes `Interval / f64` the same way postgres does,
+    /// cascading any fractional months down into days and any fractional days down into
+    /// the time part. The time part is rounded to the nearest microsecond. Will return
+    /// `None` if the factor is zero, not a number or the result is out of range.
+    ///
+    /// Dividing an infinite interval keeps it infinite, flipping the sign for a negative
+    /// factor, while dividing it by an infinite factor is `None`.
+    pub fn checked_div_f64(self, factor: f64) -> Option<Interval> {
+        if factor == 0.0 || factor.is_nan() {
+            return None;
+        }
+        if self.is_infinite() {
+            if factor.is_infinite() {
+                return None;
+            }
+            return if factor < 0.0 {
+                self.checked_neg()
+            } else {
+                Some(self)
+            };
+        }
+        let months = self.months as f64 / factor;
+        let days = self.days as f64 / factor;
+        let time = self.microseconds as f64 / factor;
+        cascade(months, days, time)
+    }
+
+    /// Checked interval division by an integer. Postgres converts the integer to a
+    /// double before dividing, so this behaves exactly like `checked_div_f64`.
+    pub fn checked_div_i32(self, factor: i32) -> Option<Interval> {
+        self.checked_div_f64(factor as f64)
+    }
+
+    /// Computes the ratio between two intervals using the postgres comparison value,
+    /// that is treating months as 30 days and days as 24 hours. Will return `None` if
+    /// the other interval is zero or either interval is infinite.
+    pub fn ratio(self, other_interval: Interval) -> Option<f64> {
+        let divisor = other_interval.cmp_value();
+        if divisor == 0 || self.is_infinite() || other_interval.is_infinite() {
+            None
+        } else {
+            Some(self.cmp_value() as f64 / divisor as f64)
+        }
+    }
+}
+
+/// Builds the interval from the scaled months, days and microseconds. Mirrors the
+/// shared part of postgres' `interval_mul` and `interval_div`, only ever cascading
+/// fractions down to the smaller units and never up.
+fn cascade(months: f64, days: f64, time: f64) -> Option<Interval> {
+    if !fits_in_i32(months) || !fits_in_i32(days) {
+        return None;
+    }
+    let whole_months = months as i32;
+    let mut whole_days = days as i32;
+    // postgres rounds the remainders to microsecond precision so
+    // floating point noise doesn't leak into the whole units.
+    let month_remainder_days = ts_round((months - whole_months as f64) * DAYS_PER_MONTH as f64);
+    let mut sec_remainder = ts_round(
+        (days - whole_days as f64 + month_remainder_days - month_remainder_days.trunc())
+            * SECS_PER_DAY as f64,
+    );
+    // the cascade from the months and days can leave us with 24 hours
+    // or more, so move those into the days.
+    if sec_remainder.abs() >= SECS_PER_DAY as f64 {
+        let extra_days = (sec_remainder / SECS_PER_DAY as f64) as i32;
+        whole_days = whole_days.checked_add(extra_days)?;
+        sec_remainder -= extra_days as f64 * SECS_PER_DAY as f64;
+    }
+    whole_days = whole_days.checked_add(month_remainder_days as i32)?;
+    let microseconds = (time + sec_remainder * MICROS_PER_SECOND as f64).round_ties_even();
+    if !fits_in_i64(microseconds) {
+        return None;
+    }
+    Some(Interval {
+        months: whole_months,
+        days: whole_days,
+        microseconds: microseconds as i64,
+    })
+    .filter(Interval::is_finite)
+}
+
+/// Rounds the value to microsecond precision, same as postgres' `TSROUND`.
+fn ts_round(val: f64) -> f64 {
+    (val * MICROS_PER_SECOND as f64).round_ties_even() / MICROS_PER_SECOND as f64
+}
+
+fn fits_in_i32(val: f64) -> bool {
+    !val.is_nan() && val >= i32::MIN as f64 && val < -(i32::MIN as f64)
+}
+
+fn fits_in_i64(val: f64) -> bool {
+    !val.is_nan() && val >= i64::MIN as f64 && val < -(i64::MIN as f64)
+}
+
+impl ops::Mul<f64> for Interval {
+    type Output = Interval;
+    fn mul(self, factor: f64) -> Interval {
+        self.checked_mul_f64(factor).expect("interval out of range")
+    }
+}
+
+impl ops::Mul<Interval> for f64 {
+    type Output = Interval;
+    fn mul(self, interval: Interval) -> Interval {
+        interval * self
+    }
+}
+
+impl ops::Mul<i32> for Interval {
+    type Output = Interval;
+    fn mul(self, factor: i32) -> Interval {
+        self.checked_mul_i32(factor).expect("interval out of range")
+    }
+}
+
+impl ops::Div<f64> for Interval {
+    type Output = Interval;
+    fn div(self, factor: f64) -> Interval {
+        self.checked_div_f64(factor).expect("interval out of range")
+    }
+}
+
+impl ops::Div<i32> for Interval {
+    type Output = Interval;
+    fn div(self, factor: i32) -> Interval {
+        self.checked_div_i32(factor).expect("interval out of range")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3_600_000_000;
+
+    #[test]
+    fn test_mul_hours() {
+        // interval '1 hour' * 3.5 = '03:30:00'
+        let interval = Interval::new(0, 0, HOUR);
+        assert_eq!(interval * 3.5, Interval::new(0, 0, 7 * HOUR / 2));
+    }
+
+    #[test]
+    fn test_mul_days() {
+        // 21 * interval '1 day' = '21 days'
+        let interval = Interval::new(0, 1, 0);
+        assert_eq!(21.0 * interval, Interval::new(0, 21, 0));
+        assert_eq!(interval * 21, Interval::new(0, 21, 0));
+    }
+
+    #[test]
+    fn test_mul_cascades_fractions() {
+        // interval '41 mon 12 days 360:00' * 0.3 = '1 year 12 days 122:24:00'
+        let interval = Interval::new(41, 12, 360 * HOUR);
+        let result = interval.checked_mul_f64(0.3);
+        assert_eq!(
+            result,
+            Some(Interval::new(12, 12, 122 * HOUR + 24 * 60_000_000))
+        );
+    }
+
+    #[test]
+    fn test_mul_cascades_mixed_sign() {
+        // interval '-41 mon -12 days +360:00' * 0.3 = '-1 years -12 days +93:36:00'
+        let interval = Interval::new(-41, -12, 360 * HOUR);
+        let result = interval.checked_mul_f64(0.3);
+        assert_eq!(
+            result,
+            Some(Interval::new(-12, -12, 93 * HOUR + 36 * 60_000_000))
+        );
+    }
+
+    #[test]
+    fn test_mul_rounds_to_microseconds() {
+        let interval = Interval::new(0, 0, 1);
+        assert_eq!(interval.checked_mul_f64(0.5), Some(Interval::new(0, 0, 0)));
+        assert_eq!(interval.checked_mul_f64(1.5), Some(Interval::new(0, 0, 2)));
+    }
+
+    #[test]
+    fn test_mul_nan() {
+        let interval = Interval::new(1, 1, 1);
+        assert_eq!(interval.checked_mul_f64(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_mul_overflow() {
+        let interval = Interval::new(i32::MAX, 0, 0);
+        assert_eq!(interval.checked_mul_f64(2.0), None);
+        assert_eq!(interval.checked_mul_i32(2), None);
+        let interval = Interval::new(0, 0, i64::MAX);
+        assert_eq!(interval.checked_mul_f64(2.0), None);
+    }
+
+    #[test]
+    fn test_mul_by_infinity() {
+        // interval '1 day' * 'infinity'::float8 = 'infinity'
+        let interval = Interval::new(0, 1, 0);
+        assert_eq!(
+            interval.checked_mul_f64(f64::INFINITY),
+            Some(Interval::INFINITY)
+        );
+        assert_eq!(
+            interval.checked_mul_f64(f64::NEG_INFINITY),
+            Some(Interval::NEG_INFINITY)
+        );
+        // interval '-1 mon 1 day' * 'infinity'::float8 = '-infinity'
+        let interval = Interval::new(-1, 1, 0);
+        assert_eq!(
+            interval.checked_mul_f64(f64::INFINITY),
+            Some(Interval::NEG_INFINITY)
+        );
+        assert_eq!(Interval::new(0, 0, 0).checked_mul_f64(f64::INFINITY), None);
+    }
+
+    #[test]
+    fn test_mul_infinite_interval() {
+        assert_eq!(Interval::INFINITY * 2.5, Interval::INFINITY);
+        assert_eq!(Interval::INFINITY * -1, Interval::NEG_INFINITY);
+        assert_eq!(
+            Interval::NEG_INFINITY.checked_mul_f64(f64::INFINITY),
+            Some(Interval::NEG_INFINITY)
+        );
+        assert_eq!(Interval::INFINITY.checked_mul_f64(0.0), None);
+        assert_eq!(Interval::INFINITY.checked_mul_f64(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_mul_reaching_infinity() {
+        // the time part rounds to i64::MIN making the result look like -infinity
+        let interval = Interval::new(i32::MIN, i32::MIN, i64::MIN + 1);
+        assert_eq!(interval.checked_mul_f64(1.0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "interval out of range")]
+    fn test_mul_overflow_panics() {
+        let _ = Interval::new(i32::MAX, 0, 0) * 2.0;
+    }
+
+    #[test]
+    fn test_div_hours() {
+        // interval '1 hour' / 1.5 = '00:40:00'
+        let interval = Interval::new(0, 0, HOUR);
+        assert_eq!(interval / 1.5, Interval::new(0, 0, 40 * 60_000_000));
+    }
+
+    #[test]
+    fn test_div_month() {
+        // interval '1 mon' / 3 = '10 days'
+        let interval = Interval::new(1, 0, 0);
+        assert_eq!(interval / 3, Interval::new(0, 10, 0));
+    }
+
+    #[test]
+    fn test_div_cascades_fractions() {
+        // interval '41 mon 12 days 360:00' / 10 = '4 mons 4 days 40:48:00'
+        let interval = Interval::new(41, 12, 360 * HOUR);
+        let result = interval.checked_div_f64(10.0);
+        assert_eq!(
+            result,
+            Some(Interval::new(4, 4, 40 * HOUR + 48 * 60_000_000))
+        );
+    }
+
+    #[test]
+    fn test_div_cascades_mixed_sign() {
+        // interval '9 mon -27 days 12:34:56' / 10 = '25 days -15:32:30.4'
+        let interval = Interval::new(9, -27, 45_296_000_000);
+        let result = interval.checked_div_i32(10);
+        assert_eq!(result, Some(Interval::new(0, 25, -55_950_400_000)));
+    }
+
+    #[test]
+    fn test_div_by_zero() {
+        let interval = Interval::new(1, 1, 1);
+        assert_eq!(interval.checked_div_f64(0.0), None);
+        assert_eq!(interval.checked_div_i32(0), None);
+        assert_eq!(interval.checked_div_f64(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_div_by_infinity() {
+        let interval = Interval::new(1, 1, 1);
+        assert_eq!(
+            interval.checked_div_f64(f64::INFINITY),
+            Some(Interval::new(0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_div_infinite_interval() {
+        assert_eq!(Interval::INFINITY / 2.0, Interval::INFINITY);
+        assert_eq!(Interval::NEG_INFINITY / -3, Interval::INFINITY);
+        assert_eq!(Interval::INFINITY.checked_div_f64(0.0), None);
+        assert_eq!(Interval::INFINITY.checked_div_f64(f64::INFINITY), None);
+    }
+
+    #[test]
+    fn test_div_overflow() {
+        let interval = Interval::new(i32::MIN, 0, 0);
+        assert_eq!(interval.checked_div_f64(-1.0), None);
+    }
+
+    #[test]
+    fn test_ratio() {
+        let month = Interval::new(1, 0, 0);
+        let days = Interval::new(0, 15, 0);
+        assert_eq!(month.ratio(days), Some(2.0));
+        assert_eq!(days.ratio(month), Some(0.5));
+        let hours = Interval::new(0, 0, -6 * HOUR);
+        assert_eq!(hours.ratio(Interval::new(0, 1, 0)), Some(-0.25));
+    }
+
+    #[test]
+    fn test_ratio_infinite() {
+        let interval = Interval::new(1, 0, 0);
+        assert_eq!(interval.ratio(Interval::INFINITY), None);
+        assert_eq!(Interval::NEG_INFINITY.ratio(interval), None);
+    }
+
+    #[test]
+    fn test_ratio_zero() {
+        let interval = Interval::new(1, 0, 0);
+        assert_eq!(interval.ratio(Interval::new(1, -30, 0)), None);
+    }
+}
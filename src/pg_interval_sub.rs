@@ -3,13 +3,42 @@ use std::ops;
 
 impl Interval {
     /// Checked interval subtraction. Computes `Interval - Interval` and `None` if there
-    /// was an underflow.
+    /// was an underflow. Like postgres subtracting an infinity from itself is `None`,
+    /// and a finite difference that would land on one of the infinities is treated as
+    /// an overflow.
     pub fn checked_sub(self, other_interval: Interval) -> Option<Interval> {
-        Some(Interval {
+        if self.is_infinite() {
+            if other_interval == self {
+                return None;
+            }
+            return Some(self);
+        }
+        if other_interval.is_infinite() {
+            return other_interval.checked_neg();
+        }
+        let interval = Interval {
             months: self.months.checked_sub(other_interval.months)?,
             days: self.days.checked_sub(other_interval.days)?,
             microseconds: self.microseconds.checked_sub(other_interval.microseconds)?,
-        })
+        };
+        interval.is_finite().then_some(interval)
+    }
+
+    /// Checked interval negation. Computes `-Interval` and `None` if any of the parts
+    /// can not be negated. Negating `infinity` gives `-infinity` and vice versa.
+    pub fn checked_neg(self) -> Option<Interval> {
+        if self == Interval::INFINITY {
+            return Some(Interval::NEG_INFINITY);
+        }
+        if self == Interval::NEG_INFINITY {
+            return Some(Interval::INFINITY);
+        }
+        let interval = Interval {
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            microseconds: self.microseconds.checked_neg()?,
+        };
+        interval.is_finite().then_some(interval)
     }
 
     /// Shortcut method to subtract day time part to the interval. Any units smaller than
@@ -76,11 +105,15 @@ impl Interval {
 impl ops::Sub for Interval {
     type Output = Interval;
     fn sub(self, other_interval: Interval) -> Interval {
-        Interval {
-            months: self.months - other_interval.months,
-            days: self.days - other_interval.days,
-            microseconds: self.microseconds - other_interval.microseconds,
-        }
+        self.checked_sub(other_interval)
+            .expect("interval out of range")
+    }
+}
+
+impl ops::Neg for Interval {
+    type Output = Interval;
+    fn neg(self) -> Interval {
+        self.checked_neg().expect("interval out of range")
     }
 }
 
@@ -88,6 +121,55 @@ impl ops::Sub for Interval {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_neg() {
+        let interval = Interval::new(1, -2, 3);
+        assert_eq!(-interval, Interval::new(-1, 2, -3));
+    }
+
+    #[test]
+    fn test_checked_neg() {
+        let interval = Interval::new(1, -2, 3);
+        assert_eq!(interval.checked_neg(), Some(Interval::new(-1, 2, -3)));
+        let interval = Interval::new(0, i32::MIN, 0);
+        assert_eq!(interval.checked_neg(), None);
+    }
+
+    #[test]
+    fn test_neg_infinity() {
+        assert_eq!(-Interval::INFINITY, Interval::NEG_INFINITY);
+        assert_eq!(-Interval::NEG_INFINITY, Interval::INFINITY);
+        // negating a finite interval never lands on an infinity
+        let interval = Interval::new(i32::MIN + 1, i32::MIN + 1, i64::MIN + 1);
+        assert_eq!(interval.checked_neg(), None);
+    }
+
+    #[test]
+    fn test_checked_sub_infinity() {
+        let interval = Interval::new(1, 2, 3);
+        assert_eq!(
+            interval.checked_sub(Interval::INFINITY),
+            Some(Interval::NEG_INFINITY)
+        );
+        assert_eq!(
+            interval.checked_sub(Interval::NEG_INFINITY),
+            Some(Interval::INFINITY)
+        );
+        assert_eq!(
+            Interval::INFINITY.checked_sub(interval),
+            Some(Interval::INFINITY)
+        );
+        assert_eq!(
+            Interval::INFINITY.checked_sub(Interval::NEG_INFINITY),
+            Some(Interval::INFINITY)
+        );
+        assert_eq!(Interval::INFINITY.checked_sub(Interval::INFINITY), None);
+        assert_eq!(
+            Interval::NEG_INFINITY.checked_sub(Interval::NEG_INFINITY),
+            None
+        );
+    }
+
     #[test]
     fn test_sub_day_time() {
         let interval = Interval::new(13, 0, 0);
@@ -0,0 +1,206 @@
+use crate::interval_norm::IntervalNorm;
+use crate::{Interval, IntervalField};
+
+impl Interval {
+    /// Truncates the interval to the precision of the field the same way postgres'
+    /// `date_trunc` does. Each part is truncated towards zero so negative intervals
+    /// truncate to the negative of the truncated sign-reversed interval. Will return
+    /// `None` for `Epoch` which postgres doesn't support for intervals.
+    /// Infinite intervals are returned unchanged.
+    pub fn trunc(&self, field: IntervalField) -> Option<Interval> {
+        let precision = match field {
+            IntervalField::Epoch => return None,
+            IntervalField::Microseconds => 0,
+            IntervalField::Milliseconds => 1,
+            IntervalField::Second => 2,
+            IntervalField::Minute => 3,
+            IntervalField::Hour => 4,
+            IntervalField::Day => 5,
+            IntervalField::Month => 6,
+            IntervalField::Quarter => 7,
+            IntervalField::Year => 8,
+            IntervalField::Decade => 9,
+            IntervalField::Century => 10,
+            IntervalField::Millennium => 11,
+        };
+        if self.is_infinite() {
+            return Some(*self);
+        }
+        let mut norm = IntervalNorm::from(self);
+        match field {
+            IntervalField::Millennium => norm.years = norm.years / 1000 * 1000,
+            IntervalField::Century => norm.years = norm.years / 100 * 100,
+            IntervalField::Decade => norm.years = norm.years / 10 * 10,
+            _ => {}
+        }
+        if precision >= 8 {
+            norm.months = 0;
+        }
+        if precision >= 7 {
+            norm.months = norm.months / 3 * 3;
+        }
+        if precision >= 6 {
+            norm.days = 0;
+        }
+        if precision >= 5 {
+            norm.hours = 0;
+        }
+        if precision >= 4 {
+            norm.minutes = 0;
+        }
+        if precision >= 3 {
+            norm.seconds = 0;
+        }
+        if precision >= 2 {
+            norm.microseconds = 0;
+        }
+        if precision == 1 {
+            norm.microseconds = norm.microseconds / 1000 * 1000;
+        }
+        norm.try_into_interval().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3_600_000_000;
+    const MINUTE: i64 = 60_000_000;
+
+    #[test]
+    fn test_trunc_time_fields() {
+        // interval '2 days 03:40:12.345678'
+        let interval = Interval::new(0, 2, 3 * HOUR + 40 * MINUTE + 12_345_678);
+        assert_eq!(interval.trunc(IntervalField::Microseconds), Some(interval));
+        assert_eq!(
+            interval.trunc(IntervalField::Milliseconds),
+            Some(Interval::new(0, 2, 3 * HOUR + 40 * MINUTE + 12_345_000))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Second),
+            Some(Interval::new(0, 2, 3 * HOUR + 40 * MINUTE + 12_000_000))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Minute),
+            Some(Interval::new(0, 2, 3 * HOUR + 40 * MINUTE))
+        );
+        // date_trunc('hour', interval '2 days 03:40:12.345678') = '2 days 03:00:00'
+        assert_eq!(
+            interval.trunc(IntervalField::Hour),
+            Some(Interval::new(0, 2, 3 * HOUR))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Day),
+            Some(Interval::new(0, 2, 0))
+        );
+    }
+
+    #[test]
+    fn test_trunc_date_fields() {
+        // interval '2015 years 5 mons 3 days 04:05:00'
+        let interval = Interval::new(2015 * 12 + 5, 3, 4 * HOUR + 5 * MINUTE);
+        assert_eq!(
+            interval.trunc(IntervalField::Month),
+            Some(Interval::new(2015 * 12 + 5, 0, 0))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Quarter),
+            Some(Interval::new(2015 * 12 + 3, 0, 0))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Year),
+            Some(Interval::new(2015 * 12, 0, 0))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Decade),
+            Some(Interval::new(2010 * 12, 0, 0))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Century),
+            Some(Interval::new(2000 * 12, 0, 0))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Millennium),
+            Some(Interval::new(2000 * 12, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_trunc_negative() {
+        // interval '-1015 years -5 mons -3 days -04:05:00.1234'
+        let interval = Interval::new(-(1015 * 12 + 5), -3, -(4 * HOUR + 5 * MINUTE + 123_400));
+        assert_eq!(
+            interval.trunc(IntervalField::Milliseconds),
+            Some(Interval::new(
+                -(1015 * 12 + 5),
+                -3,
+                -(4 * HOUR + 5 * MINUTE + 123_000)
+            ))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Hour),
+            Some(Interval::new(-(1015 * 12 + 5), -3, -4 * HOUR))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Quarter),
+            Some(Interval::new(-(1015 * 12 + 3), 0, 0))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Decade),
+            Some(Interval::new(-1010 * 12, 0, 0))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Millennium),
+            Some(Interval::new(-1000 * 12, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_trunc_mixed_sign() {
+        // date_trunc('day', interval '1 mon -2 days 03:00') = '1 mon -2 days'
+        let interval = Interval::new(1, -2, 3 * HOUR);
+        assert_eq!(
+            interval.trunc(IntervalField::Day),
+            Some(Interval::new(1, -2, 0))
+        );
+        assert_eq!(
+            interval.trunc(IntervalField::Year),
+            Some(Interval::new(0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_trunc_unsupported() {
+        let interval = Interval::new(1, 2, 3);
+        assert_eq!(interval.trunc(IntervalField::Epoch), None);
+    }
+
+    #[test]
+    fn test_trunc_infinity() {
+        // date_trunc('hour', interval 'infinity') = 'infinity'
+        assert_eq!(
+            Interval::INFINITY.trunc(IntervalField::Hour),
+            Some(Interval::INFINITY)
+        );
+        assert_eq!(
+            Interval::NEG_INFINITY.trunc(IntervalField::Year),
+            Some(Interval::NEG_INFINITY)
+        );
+        assert_eq!(Interval::INFINITY.trunc(IntervalField::Epoch), None);
+    }
+
+    #[test]
+    fn test_trunc_extremes() {
+        let interval = Interval::new(i32::MIN, i32::MIN, i64::MIN + 1);
+        assert_eq!(
+            interval.trunc(IntervalField::Day),
+            Some(Interval::new(i32::MIN, i32::MIN, 0))
+        );
+        let interval = Interval::new(i32::MAX, i32::MAX, i64::MAX - 1);
+        assert_eq!(
+            interval.trunc(IntervalField::Year),
+            Some(Interval::new(i32::MAX / 12 * 12, 0, 0))
+        );
+    }
+}
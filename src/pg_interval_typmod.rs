@@ -0,0 +1,431 @@
+use crate::integrations::conversion_error::ConversionError;
+use crate::pg_interval::{MICROS_PER_HOUR, MICROS_PER_MINUTE, MONTHS_PER_YEAR};
+use crate::{Interval, ParseError};
+use std::str::FromStr;
+
+const MAX_PRECISION: u32 = 6;
+
+// the bits postgres uses for each field in the range part of the typmod.
+const MONTH_MASK: i32 = 1 << 1;
+const YEAR_MASK: i32 = 1 << 2;
+const DAY_MASK: i32 = 1 << 3;
+const HOUR_MASK: i32 = 1 << 10;
+const MINUTE_MASK: i32 = 1 << 11;
+const SECOND_MASK: i32 = 1 << 12;
+const FULL_RANGE: i32 = 0x7FFF;
+const FULL_PRECISION: i32 = 0xFFFF;
+
+/// The fields an interval column can be restricted to, e.g. `INTERVAL DAY TO SECOND`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IntervalFields {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    YearToMonth,
+    DayToHour,
+    DayToMinute,
+    DayToSecond,
+    HourToMinute,
+    HourToSecond,
+    MinuteToSecond,
+}
+
+impl IntervalFields {
+    const ALL: [IntervalFields; 13] = [
+        IntervalFields::Year,
+        IntervalFields::Month,
+        IntervalFields::Day,
+        IntervalFields::Hour,
+        IntervalFields::Minute,
+        IntervalFields::Second,
+        IntervalFields::YearToMonth,
+        IntervalFields::DayToHour,
+        IntervalFields::DayToMinute,
+        IntervalFields::DayToSecond,
+        IntervalFields::HourToMinute,
+        IntervalFields::HourToSecond,
+        IntervalFields::MinuteToSecond,
+    ];
+
+    /// The range bits postgres stores in the typmod for the fields.
+    fn mask(self) -> i32 {
+        match self {
+            IntervalFields::Year => YEAR_MASK,
+            IntervalFields::Month => MONTH_MASK,
+            IntervalFields::Day => DAY_MASK,
+            IntervalFields::Hour => HOUR_MASK,
+            IntervalFields::Minute => MINUTE_MASK,
+            IntervalFields::Second => SECOND_MASK,
+            IntervalFields::YearToMonth => YEAR_MASK | MONTH_MASK,
+            IntervalFields::DayToHour => DAY_MASK | HOUR_MASK,
+            IntervalFields::DayToMinute => DAY_MASK | HOUR_MASK | MINUTE_MASK,
+            IntervalFields::DayToSecond => DAY_MASK | HOUR_MASK | MINUTE_MASK | SECOND_MASK,
+            IntervalFields::HourToMinute => HOUR_MASK | MINUTE_MASK,
+            IntervalFields::HourToSecond => HOUR_MASK | MINUTE_MASK | SECOND_MASK,
+            IntervalFields::MinuteToSecond => MINUTE_MASK | SECOND_MASK,
+        }
+    }
+
+    /// The unit postgres gives a number without a unit, which is the rightmost
+    /// field of the range.
+    pub(crate) fn unit(fields: Option<IntervalFields>) -> &'static str {
+        match fields {
+            Some(IntervalFields::Year) => "years",
+            Some(IntervalFields::Month | IntervalFields::YearToMonth) => "months",
+            Some(IntervalFields::Day) => "days",
+            Some(IntervalFields::Hour | IntervalFields::DayToHour) => "hours",
+            Some(
+                IntervalFields::Minute | IntervalFields::DayToMinute | IntervalFields::HourToMinute,
+            ) => "minutes",
+            _ => "seconds",
+        }
+    }
+}
+
+impl FromStr for IntervalFields {
+    type Err = ParseError;
+
+    /// Parses the fields the way they are written in a column type, such as
+    /// `DAY TO SECOND`, ignoring case.
+    fn from_str(fields: &str) -> Result<IntervalFields, ParseError> {
+        let words = fields
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect::<Vec<String>>();
+        let words = words.iter().map(String::as_str).collect::<Vec<&str>>();
+        match words.as_slice() {
+            ["year"] => Ok(IntervalFields::Year),
+            ["month"] => Ok(IntervalFields::Month),
+            ["day"] => Ok(IntervalFields::Day),
+            ["hour"] => Ok(IntervalFields::Hour),
+            ["minute"] => Ok(IntervalFields::Minute),
+            ["second"] => Ok(IntervalFields::Second),
+            ["year", "to", "month"] => Ok(IntervalFields::YearToMonth),
+            ["day", "to", "hour"] => Ok(IntervalFields::DayToHour),
+            ["day", "to", "minute"] => Ok(IntervalFields::DayToMinute),
+            ["day", "to", "second"] => Ok(IntervalFields::DayToSecond),
+            ["hour", "to", "minute"] => Ok(IntervalFields::HourToMinute),
+            ["hour", "to", "second"] => Ok(IntervalFields::HourToSecond),
+            ["minute", "to", "second"] => Ok(IntervalFields::MinuteToSecond),
+            _ => Err(ParseError::from_invalid_interval(&format!(
+                "Interval fields \"{}\" not recognized.",
+                fields
+            ))),
+        }
+    }
+}
+
+/// The type modifier of an interval column, restricting the fields it stores and
+/// the precision of its fractional seconds, e.g. `INTERVAL DAY TO SECOND(3)`.
+/// The default is an unrestricted `INTERVAL`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalTypmod {
+    fields: Option<IntervalFields>,
+    precision: Option<u32>,
+}
+
+impl IntervalTypmod {
+    /// A plain `INTERVAL` which keeps every field at full precision.
+    pub const UNRESTRICTED: IntervalTypmod = IntervalTypmod {
+        fields: None,
+        precision: None,
+    };
+
+    /// Create a new typmod from the fields and the number of fractional digits to
+    /// keep for the seconds. Will return an error if the precision is more than 6.
+    pub fn new(
+        fields: Option<IntervalFields>,
+        precision: Option<u32>,
+    ) -> Result<IntervalTypmod, ConversionError> {
+        match precision {
+            Some(precision) if precision > MAX_PRECISION => Err(
+                ConversionError::from_out_of_range("Interval precision must be between 0 and 6."),
+            ),
+            _ => Ok(IntervalTypmod { fields, precision }),
+        }
+    }
+
+    /// The fields the interval is restricted to, `None` if every field is kept.
+    pub fn fields(&self) -> Option<IntervalFields> {
+        self.fields
+    }
+
+    /// The number of fractional digits kept for the seconds, `None` if they are
+    /// kept at full precision.
+    pub fn precision(&self) -> Option<u32> {
+        self.precision
+    }
+}
+
+impl From<IntervalFields> for IntervalTypmod {
+    fn from(fields: IntervalFields) -> IntervalTypmod {
+        IntervalTypmod {
+            fields: Some(fields),
+            precision: None,
+        }
+    }
+}
+
+impl TryFrom<i32> for IntervalTypmod {
+    type Error = ConversionError;
+    /// Decodes the typmod postgres stores for the column, such as the `atttypmod`
+    /// in `pg_attribute`. A negative typmod is an unrestricted interval.
+    fn try_from(typmod: i32) -> Result<IntervalTypmod, ConversionError> {
+        if typmod < 0 {
+            return Ok(IntervalTypmod::UNRESTRICTED);
+        }
+        let range = (typmod >> 16) & FULL_RANGE;
+        let fields = if range == FULL_RANGE {
+            None
+        } else {
+            let fields = IntervalFields::ALL
+                .into_iter()
+                .find(|fields| fields.mask() == range)
+                .ok_or_else(|| {
+                    ConversionError::from_unrepresentable("Unrecognized interval typmod.")
+                })?;
+            Some(fields)
+        };
+        let precision = typmod & FULL_PRECISION;
+        let precision = if precision == FULL_PRECISION {
+            None
+        } else {
+            Some(precision as u32)
+        };
+        IntervalTypmod::new(fields, precision)
+    }
+}
+
+impl From<IntervalTypmod> for i32 {
+    /// Encodes the typmod the same way postgres does.
+    fn from(typmod: IntervalTypmod) -> i32 {
+        if typmod == IntervalTypmod::UNRESTRICTED {
+            return -1;
+        }
+        let range = typmod.fields.map_or(FULL_RANGE, IntervalFields::mask);
+        let precision = typmod.precision.map_or(FULL_PRECISION, |p| p as i32);
+        (range << 16) | precision
+    }
+}
+
+impl Interval {
+    /// Adjusts the interval to fit a column with the typmod, mirroring postgres'
+    /// `AdjustIntervalForTypmod`. Fields outside of the typmod's range are dropped,
+    /// truncating towards zero, and the fractional seconds are rounded half away
+    /// from zero to the typmod's precision. Infinite intervals are left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if rounding the seconds overflows, use `checked_apply_typmod` instead
+    /// to handle this case.
+    pub fn apply_typmod(self, typmod: IntervalTypmod) -> Interval {
+        self.checked_apply_typmod(typmod)
+            .expect("interval out of range")
+    }
+
+    /// Checked version of `apply_typmod`. Will return `None` if rounding the
+    /// seconds overflows.
+    pub fn checked_apply_typmod(self, typmod: IntervalTypmod) -> Option<Interval> {
+        if self.is_infinite() {
+            return Some(self);
+        }
+        let mut interval = self;
+        match typmod.fields {
+            None
+            | Some(
+                IntervalFields::Second
+                | IntervalFields::DayToSecond
+                | IntervalFields::HourToSecond
+                | IntervalFields::MinuteToSecond,
+            ) => {}
+            Some(IntervalFields::Year) => {
+                interval.months = interval.months / MONTHS_PER_YEAR * MONTHS_PER_YEAR;
+                interval.days = 0;
+                interval.microseconds = 0;
+            }
+            Some(IntervalFields::Month | IntervalFields::YearToMonth) => {
+                interval.days = 0;
+                interval.microseconds = 0;
+            }
+            Some(IntervalFields::Day) => interval.microseconds = 0,
+            Some(IntervalFields::Hour | IntervalFields::DayToHour) => {
+                interval.microseconds = interval.microseconds / MICROS_PER_HOUR * MICROS_PER_HOUR;
+            }
+            Some(
+                IntervalFields::Minute | IntervalFields::DayToMinute | IntervalFields::HourToMinute,
+            ) => {
+                interval.microseconds =
+                    interval.microseconds / MICROS_PER_MINUTE * MICROS_PER_MINUTE;
+            }
+        }
+        if let Some(precision) = typmod.precision {
+            let scale = 10_i64.pow(MAX_PRECISION - precision);
+            let offset = scale / 2;
+            let rounded = if interval.microseconds >= 0 {
+                interval.microseconds.checked_add(offset)?
+            } else {
+                interval.microseconds.checked_sub(offset)?
+            };
+            interval.microseconds = rounded - rounded % scale;
+        }
+        Some(interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3_600_000_000;
+    const MINUTE: i64 = 60_000_000;
+
+    fn typmod(fields: Option<IntervalFields>, precision: Option<u32>) -> IntervalTypmod {
+        IntervalTypmod::new(fields, precision).unwrap()
+    }
+
+    #[test]
+    fn test_apply_year() {
+        // interval '2 years 14 mons 3 days 04:00' year = '3 years'
+        let interval = Interval::new(38, 3, 4 * HOUR);
+        assert_eq!(
+            interval.apply_typmod(IntervalFields::Year.into()),
+            Interval::new(36, 0, 0)
+        );
+        let interval = Interval::new(-38, 3, 4 * HOUR);
+        assert_eq!(
+            interval.apply_typmod(IntervalFields::Year.into()),
+            Interval::new(-36, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_apply_year_to_month() {
+        let interval = Interval::new(38, 3, 4 * HOUR);
+        assert_eq!(
+            interval.apply_typmod(IntervalFields::YearToMonth.into()),
+            Interval::new(38, 0, 0)
+        );
+        assert_eq!(
+            interval.apply_typmod(IntervalFields::Month.into()),
+            Interval::new(38, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_apply_day_time_fields() {
+        let interval = Interval::new(1, 2, 3 * HOUR + 4 * MINUTE + 5_678_901);
+        assert_eq!(
+            interval.apply_typmod(IntervalFields::Day.into()),
+            Interval::new(1, 2, 0)
+        );
+        assert_eq!(
+            interval.apply_typmod(IntervalFields::DayToHour.into()),
+            Interval::new(1, 2, 3 * HOUR)
+        );
+        assert_eq!(
+            interval.apply_typmod(IntervalFields::HourToMinute.into()),
+            Interval::new(1, 2, 3 * HOUR + 4 * MINUTE)
+        );
+        assert_eq!(
+            interval.apply_typmod(IntervalFields::DayToSecond.into()),
+            interval
+        );
+    }
+
+    #[test]
+    fn test_apply_precision() {
+        // interval '1.23456789 seconds' second(3) = '00:00:01.235'
+        let interval = Interval::new(0, 0, 1_234_568);
+        assert_eq!(
+            interval.apply_typmod(typmod(Some(IntervalFields::Second), Some(3))),
+            Interval::new(0, 0, 1_235_000)
+        );
+        assert_eq!(
+            interval.apply_typmod(typmod(None, Some(0))),
+            Interval::new(0, 0, 1_000_000)
+        );
+        assert_eq!(interval.apply_typmod(typmod(None, Some(6))), interval);
+    }
+
+    #[test]
+    fn test_apply_precision_rounds_half_away_from_zero() {
+        let interval = Interval::new(0, 0, 500_000);
+        assert_eq!(
+            interval.apply_typmod(typmod(None, Some(0))),
+            Interval::new(0, 0, 1_000_000)
+        );
+        let interval = Interval::new(0, 0, -1_500);
+        assert_eq!(
+            interval.apply_typmod(typmod(None, Some(2))),
+            Interval::new(0, 0, 0)
+        );
+        let interval = Interval::new(0, 0, -5_000);
+        assert_eq!(
+            interval.apply_typmod(typmod(None, Some(2))),
+            Interval::new(0, 0, -10_000)
+        );
+    }
+
+    #[test]
+    fn test_apply_precision_overflow() {
+        let interval = Interval::new(0, 0, i64::MAX - 1);
+        assert_eq!(interval.checked_apply_typmod(typmod(None, Some(0))), None);
+    }
+
+    #[test]
+    fn test_apply_infinity() {
+        let typmod = typmod(Some(IntervalFields::Year), Some(0));
+        assert_eq!(Interval::INFINITY.apply_typmod(typmod), Interval::INFINITY);
+        assert_eq!(
+            Interval::NEG_INFINITY.apply_typmod(typmod),
+            Interval::NEG_INFINITY
+        );
+    }
+
+    #[test]
+    fn test_invalid_precision() {
+        assert!(matches!(
+            IntervalTypmod::new(None, Some(7)),
+            Err(ConversionError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_raw_typmod() {
+        // select atttypmod from pg_attribute for an interval day to second(3) column
+        let raw = (7176 << 16) | 3;
+        let typmod = IntervalTypmod::try_from(raw).unwrap();
+        assert_eq!(typmod.fields(), Some(IntervalFields::DayToSecond));
+        assert_eq!(typmod.precision(), Some(3));
+        assert_eq!(i32::from(typmod), raw);
+
+        assert_eq!(
+            IntervalTypmod::try_from(-1),
+            Ok(IntervalTypmod::UNRESTRICTED)
+        );
+        assert_eq!(i32::from(IntervalTypmod::UNRESTRICTED), -1);
+        // interval(2)
+        let typmod = IntervalTypmod::try_from((0x7FFF << 16) | 2).unwrap();
+        assert_eq!(typmod, IntervalTypmod::new(None, Some(2)).unwrap());
+        // interval year
+        let typmod = IntervalTypmod::try_from((4 << 16) | 0xFFFF).unwrap();
+        assert_eq!(typmod, IntervalFields::Year.into());
+    }
+
+    #[test]
+    fn test_raw_typmod_invalid() {
+        assert!(IntervalTypmod::try_from((5 << 16) | 0xFFFF).is_err());
+        assert!(IntervalTypmod::try_from((0x7FFF << 16) | 7).is_err());
+    }
+
+    #[test]
+    fn test_fields_from_str() {
+        assert_eq!("DAY TO SECOND".parse(), Ok(IntervalFields::DayToSecond));
+        assert_eq!(" year  to month".parse(), Ok(IntervalFields::YearToMonth));
+        assert_eq!("minute".parse(), Ok(IntervalFields::Minute));
+        assert!("second to minute".parse::<IntervalFields>().is_err());
+    }
+}